@@ -1,14 +1,11 @@
 use std::sync::Arc;
 
-use ::metrics::{
-    prometheus::{self, Encoder, Registry, TextEncoder},
-    HealthChecker,
-};
+use ::metrics::prometheus::{self, Encoder, Registry, TextEncoder};
 use actix_web::{
     error::InternalError, get, http::StatusCode, web, App, HttpResponse, HttpServer, Responder,
 };
 use ports::storage::Storage;
-use services::{HealthReporter, StatusReporter};
+use services::{ContractPause, FuelChainConsistency, HealthReporter, StatusReporter};
 
 use crate::{
     config::Config,
@@ -16,16 +13,23 @@ use crate::{
     Database,
 };
 
+const SUBMISSIONS_TO_REPORT: u32 = 10;
+
 pub async fn launch_api_server(
     config: &Config,
     metrics_registry: Registry,
     storage: impl Storage + 'static,
-    fuel_health_check: HealthChecker,
-    eth_health_check: HealthChecker,
+    fuel_chain_consistency: FuelChainConsistency,
+    contract_pause: ContractPause,
+    health_reporter: HealthReporter,
 ) -> Result<()> {
     let metrics_registry = Arc::new(metrics_registry);
-    let status_reporter = Arc::new(StatusReporter::new(storage));
-    let health_reporter = Arc::new(HealthReporter::new(fuel_health_check, eth_health_check));
+    let status_reporter = Arc::new(StatusReporter::new(
+        storage,
+        fuel_chain_consistency,
+        contract_pause,
+    ));
+    let health_reporter = Arc::new(health_reporter);
     HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(Arc::clone(&metrics_registry)))
@@ -34,6 +38,7 @@ pub async fn launch_api_server(
             .service(status)
             .service(metrics)
             .service(health)
+            .service(submissions)
     })
     .bind((config.app.host, config.app.port))
     .map_err(|e| Error::Other(e.to_string()))?
@@ -62,6 +67,13 @@ async fn status(data: web::Data<Arc<StatusReporter<Database>>>) -> impl Responde
     Result::Ok(web::Json(report))
 }
 
+#[get("/submissions")]
+async fn submissions(data: web::Data<Arc<StatusReporter<Database>>>) -> impl Responder {
+    let reports = data.latest_submissions(SUBMISSIONS_TO_REPORT).await?;
+
+    Result::Ok(web::Json(reports))
+}
+
 #[get("/metrics")]
 async fn metrics(registry: web::Data<Arc<Registry>>) -> impl Responder {
     let encoder = TextEncoder::new();
@@ -77,9 +89,10 @@ async fn metrics(registry: web::Data<Arc<Registry>>) -> impl Responder {
 
     let text = String::from_utf8(buf).map_err(map_to_internal_err)?;
 
-    std::result::Result::<_, InternalError<_>>::Ok(text)
+    std::result::Result::<_, actix_web::Error>::Ok(text)
 }
 
-fn map_to_internal_err(error: impl std::error::Error) -> InternalError<String> {
-    InternalError::new(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR)
+// `actix_web::Error` boxes the response error, keeping the `Result`s small
+fn map_to_internal_err(error: impl std::error::Error) -> actix_web::Error {
+    InternalError::new(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR).into()
 }
@@ -1,9 +1,10 @@
 use std::{net::Ipv4Addr, num::NonZeroU32, path::PathBuf, str::FromStr, time::Duration};
 
-use clap::{command, Parser};
+use clap::Parser;
 use eth::{Address, Chain};
 use serde::Deserialize;
 use storage::DbConfig;
+use tracing::warn;
 use url::Url;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -18,15 +19,30 @@ pub struct FuelConfig {
     /// URL to a fuel-core graphql endpoint.
     #[serde(deserialize_with = "parse_url")]
     pub graphql_endpoint: Url,
+    /// Further fuel-core graphql endpoints, asked alongside `graphql_endpoint`. The reachable ones must serve the same blocks, blocks are only committed once all of them got to it.
+    #[serde(default, deserialize_with = "parse_urls")]
+    pub additional_graphql_endpoints: Vec<Url>,
+    /// Hex encoded, uncompressed secp256k1 public keys (64 bytes, without the `04` prefix) of the
+    /// producers whose blocks may be committed. Blocks signed by anyone else are never committed.
+    /// Left unset, blocks of any producer are committed. This is only tolerated until the next
+    /// release, which will require the keys.
+    #[serde(default, deserialize_with = "parse_public_keys")]
+    pub block_producer_public_keys: Option<Vec<[u8; 64]>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct EthConfig {
-    /// The secret key authorized by the L1 bridging contracts to post block commitments.
-    pub wallet_key: String,
-    /// URL to a Ethereum RPC endpoint.
+    /// Signs the block commitments on behalf of the account authorized by the L1 bridging contracts.
+    pub signer: SignerConfig,
+    /// URL to a Ethereum RPC endpoint. `ws`/`wss` urls subscribe to the commit events while
+    /// `http`/`https` ones poll for them using `eth_getLogs`.
     #[serde(deserialize_with = "parse_url")]
     pub rpc: Url,
+    /// Ethereum RPC endpoints to fail over to, in order of priority, should `rpc` become unhealthy.
+    #[serde(default, deserialize_with = "parse_urls")]
+    pub fallback_rpcs: Vec<Url>,
+    /// How many of the RPC endpoints must agree on the latest block number and on each commit event before they're acted upon. Unset means the endpoint in use is trusted.
+    pub rpc_quorum: Option<NonZeroU32>,
     /// Chain id of the ethereum network.
     #[serde(deserialize_with = "parse_chain_id")]
     pub chain_id: Chain,
@@ -34,6 +50,42 @@ pub struct EthConfig {
     pub state_contract_address: Address,
     /// The number of fuel blocks between ethereum commits. If set to 1, then every block should be pushed to Ethereum.
     pub commit_interval: NonZeroU32,
+    /// The maximum number of ethereum blocks covered by a single `eth_getLogs` request when catching up on past commit events.
+    pub logs_block_range: NonZeroU32,
+    /// The maximum number of missed commit epochs to backfill in a single poll of the fuel node.
+    pub max_backfill_epochs_per_poll: NonZeroU32,
+    /// The number of ethereum blocks a commit transaction may stay pending before it is replaced by one paying higher fees.
+    pub tx_replacement_after_blocks: NonZeroU32,
+    /// Upper bound, in wei, for the max fee per gas when replacing stuck commit transactions.
+    pub max_fee_per_gas: u64,
+    /// The number of ethereum blocks after which an incomplete submission without a pending transaction is sent again.
+    pub resubmit_after_blocks: NonZeroU32,
+    /// The number of ethereum blocks that must be built on top of a commit before it is considered safe from reorgs.
+    pub finality_depth: NonZeroU32,
+    /// Also posts the header and transactions of each committed fuel block in EIP-4844 blobs of the commit transaction. Requires the `blobs` feature and a local signer. Stuck blob transactions aren't replaced but sent anew once dropped.
+    #[serde(default)]
+    pub post_blobs: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SignerConfig {
+    /// Signs with the given secret key.
+    Local { key: String },
+    /// Signs with the key decrypted from a JSON keystore. The password is read from the env var
+    /// named by `password_env` or from `password_file`.
+    Keystore {
+        path: PathBuf,
+        password_env: Option<String>,
+        password_file: Option<PathBuf>,
+    },
+    /// Signs through the `eth_signTransaction` JSON-RPC method of an external signing service, e.g. Web3Signer.
+    Remote {
+        #[serde(deserialize_with = "parse_url")]
+        url: Url,
+        /// Address of the account the service signs for.
+        address: Address,
+    },
 }
 
 fn parse_chain_id<'de, D>(deserializer: D) -> Result<Chain, D::Error>
@@ -58,6 +110,63 @@ where
     })
 }
 
+fn parse_urls<'de, D>(deserializer: D) -> Result<Vec<Url>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let url_strs: Vec<String> = Deserialize::deserialize(deserializer)?;
+    url_strs
+        .iter()
+        .map(|url_str| {
+            Url::from_str(url_str).map_err(|e| {
+                let msg = format!("Failed to parse URL '{url_str}': {e};");
+                serde::de::Error::custom(msg)
+            })
+        })
+        .collect()
+}
+
+fn parse_public_keys<'de, D>(deserializer: D) -> Result<Option<Vec<[u8; 64]>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let keys: Vec<String> = Deserialize::deserialize(deserializer)?;
+    keys.iter()
+        .map(|key| {
+            let mut public_key = [0; 64];
+            hex::decode_to_slice(key.trim_start_matches("0x"), &mut public_key).map_err(|e| {
+                let msg = format!("Failed to parse public key '{key}': {e};");
+                serde::de::Error::custom(msg)
+            })?;
+
+            Ok(public_key)
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+impl FuelConfig {
+    /// All the configured graphql endpoints, highest priority first.
+    #[must_use]
+    pub fn graphql_endpoints(&self) -> Vec<Url> {
+        std::iter::once(&self.graphql_endpoint)
+            .chain(&self.additional_graphql_endpoints)
+            .cloned()
+            .collect()
+    }
+}
+
+impl EthConfig {
+    /// All the configured RPC endpoints, highest priority first.
+    #[must_use]
+    pub fn rpc_endpoints(&self) -> Vec<Url> {
+        std::iter::once(&self.rpc)
+            .chain(&self.fallback_rpcs)
+            .cloned()
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct AppConfig {
     /// Port used by the started server
@@ -72,9 +181,19 @@ pub struct AppConfig {
 pub struct InternalConfig {
     pub fuel_polling_interval: Duration,
     pub fuel_errors_before_unhealthy: usize,
+    pub fuel_submissions_to_check: u32,
     pub between_eth_event_stream_restablishing_attempts: Duration,
     pub eth_errors_before_unhealthy: usize,
+    pub eth_rpc_reprobe_interval: Duration,
     pub balance_update_interval: Duration,
+    pub tx_monitor_polling_interval: Duration,
+    pub tx_fee_increase_percentage: u64,
+    pub submission_retry_polling_interval: Duration,
+    pub max_submission_attempts: u32,
+    pub finality_tracker_polling_interval: Duration,
+    pub commit_verifier_polling_interval: Duration,
+    pub onchain_submissions_to_check: u32,
+    pub pause_tracker_polling_interval: Duration,
 }
 
 impl Default for InternalConfig {
@@ -82,9 +201,19 @@ impl Default for InternalConfig {
         Self {
             fuel_polling_interval: Duration::from_secs(3),
             fuel_errors_before_unhealthy: 3,
+            fuel_submissions_to_check: 10,
             between_eth_event_stream_restablishing_attempts: Duration::from_secs(3),
             eth_errors_before_unhealthy: 3,
+            eth_rpc_reprobe_interval: Duration::from_secs(60),
             balance_update_interval: Duration::from_secs(10),
+            tx_monitor_polling_interval: Duration::from_secs(6),
+            tx_fee_increase_percentage: 20,
+            submission_retry_polling_interval: Duration::from_secs(12),
+            max_submission_attempts: 10,
+            finality_tracker_polling_interval: Duration::from_secs(12),
+            commit_verifier_polling_interval: Duration::from_secs(30),
+            onchain_submissions_to_check: 10,
+            pause_tracker_polling_interval: Duration::from_secs(6),
         }
     }
 }
@@ -109,5 +238,26 @@ pub fn parse() -> crate::errors::Result<Config> {
         .add_source(config::Environment::with_prefix("COMMITTER").separator("__"))
         .build()?;
 
-    Ok(config.try_deserialize()?)
+    let config: Config = config.try_deserialize()?;
+    match &config.fuel.block_producer_public_keys {
+        None => warn!(
+            "`fuel.block_producer_public_keys` is not set, blocks are committed WITHOUT checking \
+             who produced them. The next release will refuse to start without it."
+        ),
+        Some(keys) if keys.is_empty() => {
+            return Err(crate::errors::Error::Other(
+                "`fuel.block_producer_public_keys` must name at least one block producer"
+                    .to_string(),
+            ));
+        }
+        Some(_) => {}
+    }
+    if config.eth.post_blobs && !cfg!(feature = "blobs") {
+        return Err(crate::errors::Error::Other(
+            "`eth.post_blobs` requires the committer to be built with the `blobs` feature"
+                .to_string(),
+        ));
+    }
+
+    Ok(config)
 }
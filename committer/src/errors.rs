@@ -5,10 +5,8 @@ use tokio::task::JoinError;
 pub enum Error {
     #[error("{0}")]
     Other(String),
-    #[error("Network Error: {0}")]
-    Network(String),
-    #[error("Storage error: {0}")]
-    Storage(String),
+    #[error(transparent)]
+    Service(#[from] services::Error),
 }
 
 impl From<serde_json::Error> for Error {
@@ -31,34 +29,19 @@ impl From<JoinError> for Error {
 
 impl From<ports::storage::Error> for Error {
     fn from(error: ports::storage::Error) -> Self {
-        Self::Storage(error.to_string())
+        Self::Service(error.into())
     }
 }
 
 impl From<ports::l1::Error> for Error {
-    fn from(value: ports::l1::Error) -> Self {
-        match value {
-            ports::l1::Error::Network(e) => Self::Network(e),
-            _ => Self::Other(value.to_string()),
-        }
+    fn from(error: ports::l1::Error) -> Self {
+        Self::Service(error.into())
     }
 }
 
 impl From<ports::fuel::Error> for Error {
-    fn from(value: ports::fuel::Error) -> Self {
-        match value {
-            ports::fuel::Error::Network(e) => Self::Network(e),
-        }
-    }
-}
-
-impl From<services::Error> for Error {
-    fn from(error: services::Error) -> Self {
-        match error {
-            services::Error::Network(e) => Self::Network(e),
-            services::Error::Storage(e) => Self::Storage(e),
-            services::Error::Other(e) => Self::Other(e),
-        }
+    fn from(error: ports::fuel::Error) -> Self {
+        Self::Service(error.into())
     }
 }
 
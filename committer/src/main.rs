@@ -8,17 +8,20 @@ use api::launch_api_server;
 use config::InternalConfig;
 use errors::Result;
 use metrics::prometheus::Registry;
+use services::HealthReporter;
 use setup::{
-    create_l1_adapter, setup_logger, setup_storage, spawn_block_watcher,
-    spawn_l1_committer_and_listener, spawn_wallet_balance_tracker,
+    create_fuel_adapter, create_l1_adapter, setup_logger, setup_storage, spawn_block_watcher,
+    spawn_commit_verifier, spawn_consistency_checker, spawn_finality_tracker,
+    spawn_l1_committer_and_listener, spawn_pause_tracker, spawn_submission_retrier,
+    spawn_transaction_monitor, spawn_wallet_balance_tracker, ServiceContext,
 };
 use tokio_util::sync::CancellationToken;
 
 use crate::setup::shut_down;
 
-pub type L1 = eth::WebsocketClient;
+pub type L1 = eth::RpcClient;
 pub type Database = storage::Postgres;
-pub type FuelApi = fuel::HttpClient;
+pub type FuelApi = fuel::MultiNodeClient;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -33,48 +36,93 @@ async fn main() -> Result<()> {
 
     let metrics_registry = Registry::default();
 
-    let (rx_fuel_block, block_watcher_handle, fuel_health_check) = spawn_block_watcher(
-        &config,
-        &internal_config,
-        storage.clone(),
-        &metrics_registry,
-        cancel_token.clone(),
-    );
+    let (tx_fuel_block, rx_fuel_block) = tokio::sync::mpsc::channel(100);
+
+    let (fuel_adapter, fuel_health_check) =
+        create_fuel_adapter(&config, &internal_config, &metrics_registry);
+    let fuel_node_health_checks = fuel_adapter.node_health_checkers();
+
+    let ctx = ServiceContext {
+        config: &config,
+        internal_config: &internal_config,
+        registry: &metrics_registry,
+        storage: &storage,
+        cancel_token: &cancel_token,
+    };
+
+    let block_watcher_handle =
+        spawn_block_watcher(ctx, fuel_adapter.clone(), tx_fuel_block.clone());
+
+    let (consistency_checker_handle, fuel_chain_consistency) =
+        spawn_consistency_checker(ctx, fuel_adapter.clone());
 
     let (ethereum_rpc, eth_health_check) =
         create_l1_adapter(&config, &internal_config, &metrics_registry).await?;
+    let eth_rpc_endpoint_health_checks = ethereum_rpc.endpoint_health_checkers();
+    let eth_rpc_quorum_health_check = ethereum_rpc.quorum_health_checker();
+
+    let (pause_tracker_handle, contract_pause) = spawn_pause_tracker(ctx, ethereum_rpc.clone());
+
+    let wallet_balance_tracker_handle = spawn_wallet_balance_tracker(ctx, ethereum_rpc.clone());
 
-    let wallet_balance_tracker_handle = spawn_wallet_balance_tracker(
-        &internal_config,
-        &metrics_registry,
+    let transaction_monitor_handle = spawn_transaction_monitor(
+        ctx,
         ethereum_rpc.clone(),
-        cancel_token.clone(),
+        fuel_adapter.clone(),
+        contract_pause.clone(),
     );
 
+    let submission_retrier_handle = spawn_submission_retrier(
+        ctx,
+        tx_fuel_block.clone(),
+        ethereum_rpc.clone(),
+        contract_pause.clone(),
+    );
+
+    let finality_tracker_handle = spawn_finality_tracker(ctx, tx_fuel_block, ethereum_rpc.clone());
+
+    let commit_verifier_handle = spawn_commit_verifier(ctx, ethereum_rpc.clone());
+
     let (committer_handle, listener_handle) = spawn_l1_committer_and_listener(
-        &internal_config,
+        ctx,
         rx_fuel_block,
         ethereum_rpc,
-        storage.clone(),
-        &metrics_registry,
-        cancel_token.clone(),
+        fuel_adapter,
+        contract_pause.clone(),
+    );
+
+    let health_reporter = HealthReporter::new(
+        fuel_health_check,
+        fuel_node_health_checks,
+        eth_health_check,
+        eth_rpc_endpoint_health_checks,
+        eth_rpc_quorum_health_check,
     );
 
     launch_api_server(
         &config,
         metrics_registry,
         storage.clone(),
-        fuel_health_check,
-        eth_health_check,
+        fuel_chain_consistency,
+        contract_pause,
+        health_reporter,
     )
     .await?;
 
     shut_down(
         cancel_token,
-        block_watcher_handle,
-        wallet_balance_tracker_handle,
-        committer_handle,
-        listener_handle,
+        vec![
+            block_watcher_handle,
+            consistency_checker_handle,
+            wallet_balance_tracker_handle,
+            transaction_monitor_handle,
+            submission_retrier_handle,
+            finality_tracker_handle,
+            commit_verifier_handle,
+            pause_tracker_handle,
+            committer_handle,
+            listener_handle,
+        ],
         storage,
     )
     .await
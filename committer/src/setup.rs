@@ -1,91 +1,259 @@
-use std::time::Duration;
+use std::{path::Path, time::Duration};
 
-use metrics::{prometheus::Registry, HealthChecker, RegistersMetrics};
-use ports::{storage::Storage, types::FuelBlock};
-use services::{BlockCommitter, BlockWatcher, CommitListener, Runner, WalletBalanceTracker};
-use tokio::{sync::mpsc::Receiver, task::JoinHandle};
+use eth::{ContractStateWatcher, L1Signer};
+use metrics::{
+    prometheus::{core::Collector, IntCounterVec, Opts, Registry},
+    HealthChecker, RegistersMetrics,
+};
+use ports::types::FuelBlock;
+use services::{
+    BlockCommitter, BlockValidator, BlockWatcher, CommitListener, CommitVerifier,
+    ConsistencyChecker, ContractPause, FinalityTracker, FuelChainConsistency, GasBumpConfig,
+    PauseTracker, Runner, SubmissionRetrier, TransactionMonitor, WalletBalanceTracker,
+};
+use tokio::{
+    sync::mpsc::{Receiver, Sender},
+    task::JoinHandle,
+};
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use crate::{
-    config::{Config, InternalConfig},
-    errors::Result,
+    config::{Config, InternalConfig, SignerConfig},
+    errors::{Error, Result},
     Database, FuelApi, L1,
 };
 
-pub fn spawn_block_watcher(
-    config: &Config,
-    internal_config: &InternalConfig,
-    storage: Database,
-    registry: &Registry,
-    cancel_token: CancellationToken,
-) -> (
-    Receiver<FuelBlock>,
-    tokio::task::JoinHandle<()>,
-    HealthChecker,
-) {
-    let (fuel_adapter, fuel_connection_health) =
-        create_fuel_adapter(config, internal_config, registry);
+/// What every spawned service is set up with.
+#[derive(Clone, Copy)]
+pub struct ServiceContext<'a> {
+    pub config: &'a Config,
+    pub internal_config: &'a InternalConfig,
+    pub registry: &'a Registry,
+    pub storage: &'a Database,
+    pub cancel_token: &'a CancellationToken,
+}
 
-    let (block_watcher, rx) = create_block_watcher(config, registry, fuel_adapter, storage);
+pub fn spawn_block_watcher(
+    ctx: ServiceContext,
+    fuel_adapter: FuelApi,
+    tx_fuel_block: Sender<FuelBlock>,
+) -> tokio::task::JoinHandle<()> {
+    let block_watcher = create_block_watcher(
+        ctx.config,
+        ctx.registry,
+        tx_fuel_block,
+        fuel_adapter,
+        ctx.storage.clone(),
+        ctx.cancel_token.clone(),
+    );
 
-    let handle = schedule_polling(
-        internal_config.fuel_polling_interval,
+    // falls back to polling whenever the fuel node can't push new blocks
+    schedule_polling(
+        ctx.internal_config.fuel_polling_interval,
         block_watcher,
         "Block Watcher",
-        cancel_token,
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    )
+}
+
+pub fn spawn_consistency_checker(
+    ctx: ServiceContext,
+    fuel_adapter: FuelApi,
+) -> (tokio::task::JoinHandle<()>, FuelChainConsistency) {
+    let consistency_checker = ConsistencyChecker::new(
+        fuel_adapter,
+        ctx.storage.clone(),
+        ctx.internal_config.fuel_submissions_to_check,
+    );
+    consistency_checker.register_metrics(ctx.registry);
+
+    let consistency = consistency_checker.consistency();
+
+    let handle = schedule_polling(
+        ctx.internal_config.fuel_polling_interval,
+        consistency_checker,
+        "Consistency Checker",
+        ctx.registry,
+        ctx.cancel_token.clone(),
     );
 
-    (rx, handle, fuel_connection_health)
+    (handle, consistency)
 }
 
-pub fn spawn_wallet_balance_tracker(
-    internal_config: &InternalConfig,
-    registry: &Registry,
-    l1: L1,
-    cancel_token: CancellationToken,
-) -> tokio::task::JoinHandle<()> {
+pub fn spawn_wallet_balance_tracker(ctx: ServiceContext, l1: L1) -> tokio::task::JoinHandle<()> {
     let wallet_balance_tracker = WalletBalanceTracker::new(l1);
 
-    wallet_balance_tracker.register_metrics(registry);
+    wallet_balance_tracker.register_metrics(ctx.registry);
 
     schedule_polling(
-        internal_config.balance_update_interval,
+        ctx.internal_config.balance_update_interval,
         wallet_balance_tracker,
         "Wallet Balance Tracker",
-        cancel_token,
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    )
+}
+
+pub fn spawn_transaction_monitor(
+    ctx: ServiceContext,
+    l1: L1,
+    fuel_adapter: FuelApi,
+    contract_pause: ContractPause,
+) -> tokio::task::JoinHandle<()> {
+    let gas_bump = GasBumpConfig {
+        replace_after_blocks: ctx.config.eth.tx_replacement_after_blocks.get(),
+        fee_increase_percentage: ctx.internal_config.tx_fee_increase_percentage,
+        max_fee_per_gas: ctx.config.eth.max_fee_per_gas,
+    };
+    // blob txs are replaced by blob txs even once the committer stopped posting blobs
+    let transaction_monitor =
+        TransactionMonitor::new(l1, ctx.storage.clone(), gas_bump, contract_pause)
+            .with_blobs(fuel_adapter);
+
+    transaction_monitor.register_metrics(ctx.registry);
+
+    schedule_polling(
+        ctx.internal_config.tx_monitor_polling_interval,
+        transaction_monitor,
+        "Transaction Monitor",
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    )
+}
+
+pub fn spawn_submission_retrier(
+    ctx: ServiceContext,
+    tx_fuel_block: Sender<FuelBlock>,
+    l1: L1,
+    contract_pause: ContractPause,
+) -> tokio::task::JoinHandle<()> {
+    let submission_retrier = SubmissionRetrier::new(
+        l1,
+        ctx.storage.clone(),
+        tx_fuel_block,
+        ctx.config.eth.resubmit_after_blocks.get(),
+        ctx.internal_config.max_submission_attempts,
+        contract_pause,
+    );
+
+    submission_retrier.register_metrics(ctx.registry);
+
+    schedule_polling(
+        ctx.internal_config.submission_retry_polling_interval,
+        submission_retrier,
+        "Submission Retrier",
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    )
+}
+
+pub fn spawn_finality_tracker(
+    ctx: ServiceContext,
+    tx_fuel_block: Sender<FuelBlock>,
+    l1: L1,
+) -> tokio::task::JoinHandle<()> {
+    let finality_tracker = FinalityTracker::new(
+        l1,
+        ctx.storage.clone(),
+        tx_fuel_block,
+        ctx.config.eth.finality_depth.get(),
+    );
+
+    finality_tracker.register_metrics(ctx.registry);
+
+    schedule_polling(
+        ctx.internal_config.finality_tracker_polling_interval,
+        finality_tracker,
+        "Finality Tracker",
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    )
+}
+
+pub fn spawn_commit_verifier(ctx: ServiceContext, l1: L1) -> tokio::task::JoinHandle<()> {
+    let commit_verifier = CommitVerifier::new(
+        l1,
+        ctx.storage.clone(),
+        ctx.config.eth.commit_interval,
+        ctx.internal_config.onchain_submissions_to_check,
+    );
+
+    commit_verifier.register_metrics(ctx.registry);
+
+    schedule_polling(
+        ctx.internal_config.commit_verifier_polling_interval,
+        commit_verifier,
+        "Commit Verifier",
+        ctx.registry,
+        ctx.cancel_token.clone(),
     )
 }
 
+pub fn spawn_pause_tracker(
+    ctx: ServiceContext,
+    l1: L1,
+) -> (tokio::task::JoinHandle<()>, ContractPause) {
+    let pause_tracker = PauseTracker::new(ContractStateWatcher::new(l1));
+    pause_tracker.register_metrics(ctx.registry);
+
+    let pause = pause_tracker.pause();
+
+    let handle = schedule_polling(
+        ctx.internal_config.pause_tracker_polling_interval,
+        pause_tracker,
+        "Pause Tracker",
+        ctx.registry,
+        ctx.cancel_token.clone(),
+    );
+
+    (handle, pause)
+}
+
 pub fn spawn_l1_committer_and_listener(
-    internal_config: &InternalConfig,
+    ctx: ServiceContext,
     rx_fuel_block: Receiver<FuelBlock>,
     l1: L1,
-    storage: Database,
-    registry: &Registry,
-    cancel_token: CancellationToken,
+    fuel_adapter: FuelApi,
+    contract_pause: ContractPause,
 ) -> (tokio::task::JoinHandle<()>, tokio::task::JoinHandle<()>) {
-    let committer_handler = create_block_committer(rx_fuel_block, l1.clone(), storage.clone());
+    let mut block_committer = BlockCommitter::new(
+        rx_fuel_block,
+        l1.clone(),
+        ctx.storage.clone(),
+        contract_pause,
+    );
+    if ctx.config.eth.post_blobs {
+        block_committer = block_committer.with_blobs(fuel_adapter.clone());
+    }
+    block_committer.register_metrics(ctx.registry);
+    let committer_handler = spawn_block_committer(block_committer);
 
-    let commit_listener = CommitListener::new(l1, storage, cancel_token.clone());
-    commit_listener.register_metrics(registry);
+    let commit_listener = CommitListener::new(
+        l1,
+        fuel_adapter,
+        ctx.storage.clone(),
+        ctx.config.eth.commit_interval,
+        ctx.cancel_token.clone(),
+    );
+    commit_listener.register_metrics(ctx.registry);
 
     let listener_handle = schedule_polling(
-        internal_config.between_eth_event_stream_restablishing_attempts,
+        ctx.internal_config
+            .between_eth_event_stream_restablishing_attempts,
         commit_listener,
         "Commit Listener",
-        cancel_token,
+        ctx.registry,
+        ctx.cancel_token.clone(),
     );
 
     (committer_handler, listener_handle)
 }
 
-fn create_block_committer(
-    rx_fuel_block: Receiver<FuelBlock>,
-    l1: L1,
-    storage: impl Storage + 'static,
+fn spawn_block_committer(
+    mut block_committer: BlockCommitter<L1, Database>,
 ) -> tokio::task::JoinHandle<()> {
-    let mut block_committer = BlockCommitter::new(rx_fuel_block, l1, storage);
     tokio::spawn(async move {
         block_committer
             .run()
@@ -99,16 +267,36 @@ pub async fn create_l1_adapter(
     internal_config: &InternalConfig,
     registry: &Registry,
 ) -> Result<(L1, HealthChecker)> {
-    let l1 = L1::connect(
-        &config.eth.rpc,
-        config.eth.chain_id,
+    let signer = match &config.eth.signer {
+        SignerConfig::Local { key } => L1Signer::local(key, config.eth.chain_id)?,
+        SignerConfig::Keystore {
+            path,
+            password_env,
+            password_file,
+        } => {
+            let password = keystore_password(password_env.as_deref(), password_file.as_deref())?;
+            L1Signer::keystore(path, &password, config.eth.chain_id)?
+        }
+        SignerConfig::Remote { url, address } => {
+            L1Signer::remote(url.clone(), *address, config.eth.chain_id)
+        }
+    };
+
+    let mut l1 = L1::connect(
+        &config.eth.rpc_endpoints(),
         config.eth.state_contract_address,
-        &config.eth.wallet_key,
+        signer,
         config.eth.commit_interval,
+        config.eth.logs_block_range,
         internal_config.eth_errors_before_unhealthy,
+        internal_config.eth_rpc_reprobe_interval,
     )
     .await?;
 
+    if let Some(quorum) = config.eth.rpc_quorum {
+        l1 = l1.with_quorum(quorum)?;
+    }
+
     l1.register_metrics(registry);
 
     let health_check = l1.connection_health_checker();
@@ -120,12 +308,21 @@ fn schedule_polling(
     polling_interval: Duration,
     mut runner: impl Runner + 'static,
     name: &'static str,
+    registry: &Registry,
     cancel_token: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
+    let metrics = RunnerMetrics::new(name);
+    metrics.register_metrics(registry);
+
     tokio::spawn(async move {
         loop {
             if let Err(e) = runner.run().await {
-                error!("{name} encountered an error: {e}");
+                metrics.runner_errors.with_label_values(&[e.kind()]).inc();
+                if e.is_retryable() {
+                    warn!("{name} encountered an error, will retry: {e}");
+                } else {
+                    error!("{name} encountered an error: {e}");
+                }
             }
 
             if cancel_token.is_cancelled() {
@@ -139,13 +336,41 @@ fn schedule_polling(
     })
 }
 
-fn create_fuel_adapter(
+#[derive(Clone)]
+struct RunnerMetrics {
+    runner_errors: IntCounterVec,
+}
+
+impl RunnerMetrics {
+    fn new(runner: &str) -> Self {
+        // every polled runner registers its own counter, told apart by the `runner` label
+        let runner_errors = IntCounterVec::new(
+            Opts::new(
+                "runner_errors",
+                "Number of errors the polled runners ran into, by kind of error.",
+            )
+            .const_label("runner", runner),
+            &["kind"],
+        )
+        .expect("runner_errors metric to be correctly configured");
+
+        Self { runner_errors }
+    }
+}
+
+impl RegistersMetrics for RunnerMetrics {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.runner_errors.clone())]
+    }
+}
+
+pub fn create_fuel_adapter(
     config: &Config,
     internal_config: &InternalConfig,
     registry: &Registry,
 ) -> (FuelApi, HealthChecker) {
     let fuel_adapter = FuelApi::new(
-        &config.fuel.graphql_endpoint,
+        &config.fuel.graphql_endpoints(),
         internal_config.fuel_errors_before_unhealthy,
     );
     fuel_adapter.register_metrics(registry);
@@ -158,19 +383,50 @@ fn create_fuel_adapter(
 fn create_block_watcher(
     config: &Config,
     registry: &Registry,
+    tx_fuel_block: Sender<FuelBlock>,
     fuel_adapter: FuelApi,
     storage: Database,
-) -> (BlockWatcher<FuelApi, Database>, Receiver<FuelBlock>) {
-    let (tx_fuel_block, rx_fuel_block) = tokio::sync::mpsc::channel(100);
+    cancel_token: CancellationToken,
+) -> BlockWatcher<FuelApi, Database> {
     let block_watcher = BlockWatcher::new(
         config.eth.commit_interval,
+        config.eth.max_backfill_epochs_per_poll,
         tx_fuel_block,
         fuel_adapter,
         storage,
+        config
+            .fuel
+            .block_producer_public_keys
+            .clone()
+            .map_or_else(BlockValidator::accepting_any_producer, BlockValidator::new),
+        cancel_token,
     );
     block_watcher.register_metrics(registry);
 
-    (block_watcher, rx_fuel_block)
+    block_watcher
+}
+
+fn keystore_password(password_env: Option<&str>, password_file: Option<&Path>) -> Result<String> {
+    match (password_env, password_file) {
+        (Some(var), None) => std::env::var(var).map_err(|e| {
+            Error::Other(format!(
+                "could not read keystore password from env var `{var}`: {e}"
+            ))
+        }),
+        (None, Some(file)) => {
+            let password = std::fs::read_to_string(file).map_err(|e| {
+                Error::Other(format!(
+                    "could not read keystore password from `{}`: {e}",
+                    file.display()
+                ))
+            })?;
+            Ok(password.trim_end_matches(['\r', '\n']).to_string())
+        }
+        _ => Err(Error::Other(
+            "exactly one of `password_env` or `password_file` must be given for the keystore"
+                .to_string(),
+        )),
+    }
 }
 
 pub fn setup_logger() {
@@ -191,20 +447,12 @@ pub async fn setup_storage(config: &Config) -> Result<Database> {
 
 pub async fn shut_down(
     cancel_token: CancellationToken,
-    block_watcher_handle: JoinHandle<()>,
-    wallet_balance_tracker_handle: JoinHandle<()>,
-    committer_handle: JoinHandle<()>,
-    listener_handle: JoinHandle<()>,
+    handles: Vec<JoinHandle<()>>,
     storage: Database,
 ) -> Result<()> {
     cancel_token.cancel();
 
-    for handle in [
-        block_watcher_handle,
-        wallet_balance_tracker_handle,
-        committer_handle,
-        listener_handle,
-    ] {
+    for handle in handles {
         handle.await?;
     }
 
@@ -3,7 +3,7 @@ mod tests {
     use std::time::Duration;
 
     use anyhow::Result;
-    use eth::{Chain, WebsocketClient};
+    use eth::{Chain, L1Signer, RpcClient};
     use fuel::HttpClient;
     use ports::fuel::Api;
 
@@ -14,13 +14,17 @@ mod tests {
         let fuel_node_address = format!("http://localhost:{FUEL_NODE_PORT}");
         let provider = HttpClient::new(&fuel_node_address.parse()?, 10);
 
-        let fuel_contract = WebsocketClient::connect(
-            &"ws://localhost:8089".parse()?,
-            Chain::AnvilHardhat,
+        let fuel_contract = RpcClient::connect(
+            &["ws://localhost:8089".parse()?],
             "0xdAad669b06d79Cb48C8cfef789972436dBe6F24d".parse()?,
-            "0x9e56ccf010fa4073274b8177ccaad46fbaf286645310d03ac9bb6afa922a7c36",
+            L1Signer::local(
+                "0x9e56ccf010fa4073274b8177ccaad46fbaf286645310d03ac9bb6afa922a7c36",
+                Chain::AnvilHardhat,
+            )?,
             3.try_into()?,
+            1000.try_into()?,
             10,
+            Duration::from_secs(60),
         )
         .await?;
 
@@ -0,0 +1,195 @@
+use c_kzg::{Blob, KzgCommitment, KzgProof, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+use ethers::{
+    types::{Address, Bytes, Signature, H256, U256},
+    utils::{keccak256, rlp::RlpStream},
+};
+use sha2::{Digest, Sha256};
+
+use crate::error::{Error, Result};
+
+const BLOB_TX_TYPE: u8 = 0x03;
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+const MAX_BLOBS_PER_TX: usize = 6;
+// The leading byte of every field element is kept zeroed so that it stays below the BLS modulus.
+const USABLE_BYTES_PER_FIELD_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
+const USABLE_BYTES_PER_BLOB: usize =
+    BYTES_PER_BLOB / BYTES_PER_FIELD_ELEMENT * USABLE_BYTES_PER_FIELD_ELEMENT;
+
+/// The blobs carrying some data, along with their KZG commitments and proofs.
+pub(crate) struct BlobSidecar {
+    blobs: Vec<Box<Blob>>,
+    commitments: Vec<[u8; 48]>,
+    proofs: Vec<[u8; 48]>,
+}
+
+impl BlobSidecar {
+    /// Spreads `data`, prefixed by its big-endian `u32` length, over as many blobs as needed.
+    pub(crate) fn new(data: &[u8]) -> Result<Self> {
+        let len = u32::try_from(data.len())
+            .map_err(|_| Error::Other(format!("{} bytes don't fit into blobs", data.len())))?;
+        let data = [&len.to_be_bytes()[..], data].concat();
+
+        let num_blobs = data.len().div_ceil(USABLE_BYTES_PER_BLOB);
+        if num_blobs > MAX_BLOBS_PER_TX {
+            return Err(Error::Other(format!(
+                "{} bytes need {num_blobs} blobs, a tx carries at most {MAX_BLOBS_PER_TX}",
+                data.len()
+            )));
+        }
+
+        let settings = c_kzg::ethereum_kzg_settings();
+        let mut sidecar = Self {
+            blobs: Vec::with_capacity(num_blobs),
+            commitments: Vec::with_capacity(num_blobs),
+            proofs: Vec::with_capacity(num_blobs),
+        };
+
+        for chunk in data.chunks(USABLE_BYTES_PER_BLOB) {
+            let mut bytes = vec![0; BYTES_PER_BLOB];
+            for (element, chunk) in bytes
+                .chunks_mut(BYTES_PER_FIELD_ELEMENT)
+                .zip(chunk.chunks(USABLE_BYTES_PER_FIELD_ELEMENT))
+            {
+                element[1..=chunk.len()].copy_from_slice(chunk);
+            }
+
+            let blob = Box::new(Blob::from_bytes(&bytes).map_err(kzg_error)?);
+            let commitment =
+                KzgCommitment::blob_to_kzg_commitment(&blob, settings).map_err(kzg_error)?;
+            let proof = KzgProof::compute_blob_kzg_proof(&blob, &commitment.to_bytes(), settings)
+                .map_err(kzg_error)?;
+
+            sidecar.blobs.push(blob);
+            sidecar.commitments.push(*commitment.to_bytes());
+            sidecar.proofs.push(*proof.to_bytes());
+        }
+
+        Ok(sidecar)
+    }
+
+    pub(crate) fn versioned_hashes(&self) -> Vec<H256> {
+        self.commitments
+            .iter()
+            .map(|commitment| {
+                let mut hash: [u8; 32] = Sha256::digest(commitment).into();
+                hash[0] = VERSIONED_HASH_VERSION_KZG;
+                H256(hash)
+            })
+            .collect()
+    }
+}
+
+fn kzg_error(err: c_kzg::Error) -> Error {
+    Error::Other(format!("could not compute the blob commitments: {err:?}"))
+}
+
+/// An EIP-4844 tx, which ethers doesn't know about and so is encoded here.
+pub(crate) struct BlobTx {
+    pub(crate) chain_id: u64,
+    pub(crate) nonce: u64,
+    pub(crate) max_priority_fee_per_gas: u64,
+    pub(crate) max_fee_per_gas: u64,
+    pub(crate) gas: U256,
+    pub(crate) to: Address,
+    pub(crate) data: Bytes,
+    pub(crate) max_fee_per_blob_gas: U256,
+    pub(crate) sidecar: BlobSidecar,
+}
+
+impl BlobTx {
+    pub(crate) fn sighash(&self) -> H256 {
+        let mut rlp = RlpStream::new_list(11);
+        self.append_fields(&mut rlp);
+
+        Self::typed_hash(&rlp.out())
+    }
+
+    /// The tx hash along with the tx as sent to the network, i.e. wrapped with its blobs.
+    pub(crate) fn encode_signed(&self, signature: &Signature) -> (H256, Bytes) {
+        let y_parity: u64 = match signature.v {
+            v @ 0..=1 => v,
+            v @ 27..=28 => v - 27,
+            v => v.saturating_sub(35) % 2,
+        };
+
+        let mut payload = RlpStream::new_list(14);
+        self.append_fields(&mut payload);
+        payload.append(&y_parity);
+        payload.append(&signature.r);
+        payload.append(&signature.s);
+        let payload = payload.out();
+
+        let mut network = RlpStream::new_list(4);
+        network.append_raw(&payload, 1);
+        network.begin_list(self.sidecar.blobs.len());
+        for blob in &self.sidecar.blobs {
+            network.append(&blob.to_vec());
+        }
+        network.begin_list(self.sidecar.commitments.len());
+        for commitment in &self.sidecar.commitments {
+            network.append(&commitment.to_vec());
+        }
+        network.begin_list(self.sidecar.proofs.len());
+        for proof in &self.sidecar.proofs {
+            network.append(&proof.to_vec());
+        }
+
+        let raw = [&[BLOB_TX_TYPE][..], &network.out()[..]].concat();
+
+        (Self::typed_hash(&payload), raw.into())
+    }
+
+    fn append_fields(&self, rlp: &mut RlpStream) {
+        rlp.append(&self.chain_id);
+        rlp.append(&self.nonce);
+        rlp.append(&self.max_priority_fee_per_gas);
+        rlp.append(&self.max_fee_per_gas);
+        rlp.append(&self.gas);
+        rlp.append(&self.to);
+        rlp.append(&U256::zero());
+        rlp.append(&self.data.as_ref());
+        // no access list
+        rlp.begin_list(0);
+        rlp.append(&self.max_fee_per_blob_gas);
+        rlp.append_list::<H256, H256>(&self.sidecar.versioned_hashes());
+    }
+
+    fn typed_hash(payload: &[u8]) -> H256 {
+        H256(keccak256([&[BLOB_TX_TYPE][..], payload].concat()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spreads_data_over_blobs() {
+        // given
+        let data = vec![1; USABLE_BYTES_PER_BLOB];
+
+        // when
+        let sidecar = BlobSidecar::new(&data).unwrap();
+
+        // then
+        assert_eq!(sidecar.blobs.len(), 2);
+        assert_eq!(sidecar.commitments.len(), 2);
+        assert_eq!(sidecar.proofs.len(), 2);
+        assert!(sidecar
+            .versioned_hashes()
+            .iter()
+            .all(|hash| hash[0] == VERSIONED_HASH_VERSION_KZG));
+    }
+
+    #[test]
+    fn rejects_data_exceeding_the_blobs_of_a_tx() {
+        // given
+        let data = vec![1; USABLE_BYTES_PER_BLOB * MAX_BLOBS_PER_TX];
+
+        // when
+        let result = BlobSidecar::new(&data);
+
+        // then
+        assert!(matches!(result, Err(Error::Other(_))));
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::RpcClient;
+
+#[derive(Debug, Clone, Copy)]
+struct SeenState {
+    height: u64,
+    paused: bool,
+}
+
+/// Follows the paused flag of the state contract. The flag itself is read only once, from then on
+/// the `Paused`/`Unpaused` events emitted since the last check are applied.
+#[derive(Clone)]
+pub struct ContractStateWatcher {
+    client: RpcClient,
+    last_seen: Arc<Mutex<Option<SeenState>>>,
+}
+
+impl ContractStateWatcher {
+    #[must_use]
+    pub fn new(client: RpcClient) -> Self {
+        Self {
+            client,
+            last_seen: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ports::l1::ContractState for ContractStateWatcher {
+    async fn paused(&self) -> ports::l1::Result<bool> {
+        let mut last_seen = self.last_seen.lock().await;
+        let height = self.client.get_block_number().await?;
+
+        let paused = match *last_seen {
+            None => self.client.paused().await?,
+            Some(seen) if seen.height >= height => seen.paused,
+            Some(seen) => self
+                .client
+                .pause_changes(seen.height + 1, height)
+                .await?
+                .unwrap_or(seen.paused),
+        };
+
+        *last_seen = Some(SeenState { height, paused });
+
+        Ok(paused)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        time::Duration,
+    };
+
+    use ethers::{
+        abi::AbiEncode,
+        contract::EthEvent,
+        types::{Address, H256},
+    };
+    use ports::l1::ContractState;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        rpc::{PausedFilter, UnpausedFilter},
+        test_utils::{given_signer, spawn_rpc_server},
+    };
+
+    #[tokio::test]
+    async fn follows_pause_events_after_reading_the_flag() {
+        // given
+        let unpaused = Arc::new(AtomicBool::new(false));
+        let url = {
+            let unpaused = Arc::clone(&unpaused);
+            spawn_rpc_server(move |method, _| match method {
+                "eth_blockNumber" if unpaused.load(Ordering::Relaxed) => json!("0x2b"),
+                "eth_blockNumber" => json!("0x2a"),
+                // the paused flag, abi encoded
+                "eth_call" => json!(true.encode_hex()),
+                "eth_getLogs" => json!([given_log(UnpausedFilter::signature(), 0x2b)]),
+                _ => Value::Null,
+            })
+            .await
+        };
+        let watcher = ContractStateWatcher::new(given_client(url).await);
+
+        let paused_initially = watcher.paused().await.unwrap();
+
+        // when
+        unpaused.store(true, Ordering::Relaxed);
+        let paused_later = watcher.paused().await.unwrap();
+
+        // then
+        assert!(paused_initially);
+        assert!(!paused_later);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_state_without_new_blocks() {
+        // given
+        let url = spawn_rpc_server(|method, _| match method {
+            "eth_blockNumber" => json!("0x2a"),
+            "eth_call" => json!(false.encode_hex()),
+            "eth_getLogs" => json!([given_log(PausedFilter::signature(), 0x2a)]),
+            _ => Value::Null,
+        })
+        .await;
+        let watcher = ContractStateWatcher::new(given_client(url).await);
+        watcher.paused().await.unwrap();
+
+        // when
+        let paused = watcher.paused().await.unwrap();
+
+        // then
+        assert!(!paused);
+    }
+
+    #[tokio::test]
+    async fn catches_up_on_pause_events_in_ranges_of_the_configured_size() {
+        // given
+        let caught_up = Arc::new(AtomicBool::new(false));
+        let requested_ranges = Arc::new(std::sync::Mutex::new(vec![]));
+        let url = {
+            let caught_up = Arc::clone(&caught_up);
+            let requested_ranges = Arc::clone(&requested_ranges);
+            spawn_rpc_server(move |method, params| match method {
+                "eth_blockNumber" if caught_up.load(Ordering::Relaxed) => json!("0x9c4"),
+                "eth_blockNumber" => json!("0x0"),
+                "eth_call" => json!(false.encode_hex()),
+                "eth_getLogs" => {
+                    requested_ranges.lock().unwrap().push((
+                        params[0]["fromBlock"].as_str().unwrap().to_string(),
+                        params[0]["toBlock"].as_str().unwrap().to_string(),
+                    ));
+                    json!([])
+                }
+                _ => Value::Null,
+            })
+            .await
+        };
+        let watcher = ContractStateWatcher::new(given_client(url).await);
+        watcher.paused().await.unwrap();
+
+        // when
+        caught_up.store(true, Ordering::Relaxed);
+        watcher.paused().await.unwrap();
+
+        // then
+        let ranges = requested_ranges.lock().unwrap().clone();
+        let expected = [("0x1", "0x3e8"), ("0x3e9", "0x7d0"), ("0x7d1", "0x9c4")]
+            .map(|(from, to)| (from.to_string(), to.to_string()));
+        assert_eq!(ranges, expected);
+    }
+
+    fn given_log(signature: H256, block_number: u64) -> Value {
+        json!({
+            "address": Address::zero(),
+            "topics": [signature],
+            "data": Address::zero().encode_hex(),
+            "blockHash": H256::repeat_byte(1),
+            "blockNumber": format!("{block_number:#x}"),
+            "transactionHash": H256::repeat_byte(2),
+            "transactionIndex": "0x0",
+            "logIndex": "0x0",
+            "removed": false,
+        })
+    }
+
+    async fn given_client(url: url::Url) -> RpcClient {
+        let signer = given_signer();
+
+        RpcClient::connect(
+            &[url],
+            Address::zero(),
+            signer,
+            1.try_into().unwrap(),
+            1000.try_into().unwrap(),
+            3,
+            Duration::MAX,
+        )
+        .await
+        .unwrap()
+    }
+}
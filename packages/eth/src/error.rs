@@ -1,46 +1,322 @@
 use ethers::{
+    abi::{self, ParamType, Token},
+    middleware::signer::SignerMiddlewareError,
     prelude::{ContractError, SignerMiddleware},
-    providers::{Provider, Ws},
-    signers::LocalWallet,
+    providers::{Provider, ProviderError, RpcError},
+    types::Bytes,
+    utils::id,
 };
 
+use crate::{rpc::Transport, signer::L1Signer};
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("wallet error: {0}")]
     Wallet(#[from] ethers::signers::WalletError),
     #[error("network error: {0}")]
     Network(String),
+    #[error("request timed out: {0}")]
+    Timeout(#[source] ProviderError),
+    #[error("rate limited by the rpc endpoint: {0}")]
+    RateLimited(#[source] ProviderError),
+    #[error("invalid data received: {0}")]
+    InvalidData(#[source] ProviderError),
+    #[error("rpc endpoints disagree: {0}")]
+    NoQuorum(String),
+    #[error("tx nonce conflicts with the ones already known: {0}")]
+    NonceConflict(String),
+    #[error("state contract is paused")]
+    ContractPaused,
+    #[error("committer is not authorized: {0}")]
+    Unauthorized(String),
+    #[error("commit height rejected: {0}")]
+    InvalidCommitHeight(String),
+    #[error("contract call reverted: {0}")]
+    Reverted(String),
     #[error("other error: {0}")]
     Other(String),
 }
 
-impl From<ethers::providers::ProviderError> for Error {
-    fn from(err: ethers::providers::ProviderError) -> Self {
-        Self::Network(err.to_string())
+// -32005 is what infura and alchemy, among others, answer with once a limit is exceeded.
+const RATE_LIMITED_CODES: [i64; 2] = [429, -32005];
+
+// What geth and most other clients answer with when a tx clashes with the nonces they know of.
+const NONCE_CONFLICT_MESSAGES: [&str; 3] = [
+    "nonce too low",
+    "already known",
+    "replacement transaction underpriced",
+];
+
+impl From<ProviderError> for Error {
+    fn from(err: ProviderError) -> Self {
+        let msg = err.to_string();
+
+        if let Some(response) = err.as_error_response() {
+            if RATE_LIMITED_CODES.contains(&response.code)
+                || response.message.to_lowercase().contains("rate limit")
+            {
+                return Self::RateLimited(err);
+            }
+
+            let message = response.message.to_lowercase();
+            if NONCE_CONFLICT_MESSAGES
+                .iter()
+                .any(|conflict| message.contains(conflict))
+            {
+                return Self::NonceConflict(msg);
+            }
+
+            // the endpoint answered, it just didn't like the request
+            return match response.as_revert_data() {
+                Some(data) if !data.is_empty() => Self::from_revert(&data),
+                Some(_) => Self::Reverted(msg),
+                None => Self::Other(msg),
+            };
+        }
+
+        match &err {
+            ProviderError::SerdeJson(_) | ProviderError::HexError(_) => Self::InvalidData(err),
+            ProviderError::HTTPError(http_err) if http_err.is_timeout() => Self::Timeout(err),
+            _ if msg.contains("timed out") => Self::Timeout(err),
+            _ => Self::Network(msg),
+        }
     }
 }
 
 pub type ContractErrorType =
-    ethers::contract::ContractError<SignerMiddleware<Provider<Ws>, LocalWallet>>;
+    ethers::contract::ContractError<SignerMiddleware<Provider<Transport>, L1Signer>>;
 
 impl From<ContractErrorType> for Error {
     fn from(value: ContractErrorType) -> Self {
         match value {
+            ContractError::Revert(data) => Self::from_revert(&data),
+            ContractError::MiddlewareError {
+                e: SignerMiddlewareError::MiddlewareError(e),
+            } => Self::from(e),
             ContractError::MiddlewareError { e } => Self::Other(e.to_string()),
-            ContractError::ProviderError { e } => Self::Network(e.to_string()),
+            ContractError::ProviderError { e } => Self::from(e),
             _ => Self::Other(value.to_string()),
         }
     }
 }
 
+impl Error {
+    /// Whether the endpoint itself is to blame, i.e. whether the request might succeed on another
+    /// endpoint or at a later time.
+    pub(crate) fn is_network(&self) -> bool {
+        matches!(
+            self,
+            Self::Network(_) | Self::Timeout(_) | Self::RateLimited(_)
+        )
+    }
+
+    /// Decodes the revert reasons the state contract is known to give, either as a reason string
+    /// or as a custom error.
+    fn from_revert(data: &Bytes) -> Self {
+        if data.len() < 4 {
+            return Self::Reverted(data.to_string());
+        }
+        let (selector, args) = data.split_at(4);
+
+        if selector == id("Error(string)") {
+            let Ok(reason) = abi::decode(&[ParamType::String], args) else {
+                return Self::Reverted(data.to_string());
+            };
+            let reason = reason
+                .into_iter()
+                .next()
+                .and_then(Token::into_string)
+                .unwrap_or_default();
+
+            return if reason.contains("Pausable: paused") {
+                Self::ContractPaused
+            } else if reason.starts_with("AccessControl:") {
+                Self::Unauthorized(reason)
+            } else {
+                Self::Reverted(reason)
+            };
+        }
+
+        if selector == id("EnforcedPause()") {
+            Self::ContractPaused
+        } else if selector == id("AccessControlUnauthorizedAccount(address,bytes32)") {
+            Self::Unauthorized(format!("account is missing a role, revert data: {data}"))
+        } else if selector == id("CannotRecommit()") {
+            Self::InvalidCommitHeight("the commit slot was committed to too recently".to_string())
+        } else {
+            Self::Reverted(data.to_string())
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 impl From<Error> for ports::l1::Error {
     fn from(err: Error) -> Self {
         match err {
             Error::Network(err) => Self::Network(err),
+            Error::Timeout(err) => Self::Timeout(Box::new(err)),
+            Error::RateLimited(err) => Self::RateLimited(Box::new(err)),
+            Error::InvalidData(err) => Self::InvalidData(Box::new(err)),
             Error::Other(err) => Self::Other(err),
+            Error::NoQuorum(err) => Self::NoQuorum(err),
+            Error::NonceConflict(err) => Self::NonceConflict(err),
             Error::Wallet(err) => Self::Other(err.to_string()),
+            Error::ContractPaused => Self::ContractPaused,
+            Error::Unauthorized(err) => Self::Unauthorized(err),
+            Error::InvalidCommitHeight(err) => Self::InvalidCommitHeight(err),
+            Error::Reverted(err) => Self::Reverted(err),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ethers::providers::{HttpClientError, JsonRpcError};
+
+    use super::*;
+
+    #[test]
+    fn decodes_reason_strings() {
+        // given
+        let revert = |reason: &str| {
+            let mut data = id("Error(string)").to_vec();
+            data.extend(abi::encode(&[Token::String(reason.to_string())]));
+            ContractErrorType::Revert(data.into())
+        };
+
+        // when
+        let paused = Error::from(revert("Pausable: paused"));
+        let unauthorized = Error::from(revert("AccessControl: account is missing role"));
+        let other = Error::from(revert("something else"));
+
+        // then
+        assert!(matches!(paused, Error::ContractPaused));
+        assert!(matches!(unauthorized, Error::Unauthorized(_)));
+        assert!(matches!(other, Error::Reverted(reason) if reason == "something else"));
+    }
+
+    #[test]
+    fn decodes_custom_errors() {
+        // given
+        let revert = |signature: &str, args: Vec<u8>| {
+            let mut data = id(signature).to_vec();
+            data.extend(args);
+            ContractErrorType::Revert(data.into())
+        };
+        let unauthorized_args = abi::encode(&[
+            Token::Address(ethers::types::Address::zero()),
+            Token::FixedBytes(vec![0; 32]),
+        ]);
+
+        // when
+        let paused = Error::from(revert("EnforcedPause()", vec![]));
+        let unauthorized = Error::from(revert(
+            "AccessControlUnauthorizedAccount(address,bytes32)",
+            unauthorized_args,
+        ));
+        let recommit = Error::from(revert("CannotRecommit()", vec![]));
+        let unknown = Error::from(revert("Unknown()", vec![]));
+
+        // then
+        assert!(matches!(paused, Error::ContractPaused));
+        assert!(matches!(unauthorized, Error::Unauthorized(_)));
+        assert!(matches!(recommit, Error::InvalidCommitHeight(_)));
+        assert!(matches!(unknown, Error::Reverted(_)));
+    }
+
+    #[test]
+    fn decodes_revert_data_of_error_responses() {
+        // given
+        let err = HttpClientError::JsonRpcError(JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: Some(serde_json::Value::String(
+                Bytes::from(id("EnforcedPause()").to_vec()).to_string(),
+            )),
+        });
+
+        // when
+        let paused = Error::from(ProviderError::JsonRpcClientError(Box::new(err)));
+
+        // then
+        assert!(matches!(paused, Error::ContractPaused));
+    }
+
+    #[test]
+    fn classifies_provider_errors() {
+        // given
+        let rpc_error = |code: i64, message: &str| {
+            let err = HttpClientError::JsonRpcError(JsonRpcError {
+                code,
+                message: message.to_string(),
+                data: None,
+            });
+            ProviderError::JsonRpcClientError(Box::new(err))
+        };
+
+        // when
+        let too_many_requests = Error::from(rpc_error(429, "too many requests"));
+        let limit_exceeded = Error::from(rpc_error(-32005, "limit exceeded"));
+        let reverted = Error::from(rpc_error(3, "execution reverted"));
+        let nonce_too_low = Error::from(rpc_error(-32000, "nonce too low"));
+        let timed_out = Error::from(ProviderError::CustomError(
+            "operation timed out".to_string(),
+        ));
+        let malformed = Error::from(ProviderError::SerdeJson(
+            serde_json::from_str::<u64>("not json").unwrap_err(),
+        ));
+
+        // then
+        assert!(matches!(too_many_requests, Error::RateLimited(_)));
+        assert!(matches!(limit_exceeded, Error::RateLimited(_)));
+        assert!(matches!(reverted, Error::Reverted(_)));
+        assert!(matches!(nonce_too_low, Error::NonceConflict(_)));
+        assert!(matches!(timed_out, Error::Timeout(_)));
+        assert!(matches!(malformed, Error::InvalidData(_)));
+        assert!([too_many_requests, timed_out].iter().all(Error::is_network));
+        assert!(![reverted, nonce_too_low, malformed]
+            .iter()
+            .any(Error::is_network));
+    }
+
+    #[test]
+    fn unwraps_provider_errors_of_the_signer_middleware() {
+        // given
+        let err = ContractErrorType::MiddlewareError {
+            e: SignerMiddlewareError::MiddlewareError(ProviderError::CustomError(
+                "connection refused".to_string(),
+            )),
+        };
+
+        // when
+        let err = Error::from(err);
+
+        // then
+        assert!(matches!(err, Error::Network(_)));
+    }
+
+    #[test]
+    fn only_errors_known_to_be_transient_are_retryable() {
+        // given
+        let retryable = [
+            Error::Network("connection refused".to_string()),
+            Error::NoQuorum("endpoints answered differently".to_string()),
+            Error::NonceConflict("nonce too low".to_string()),
+            Error::ContractPaused,
+        ];
+        let not_retryable = [
+            Error::InvalidCommitHeight("committed too recently".to_string()),
+            Error::Reverted("reason".to_string()),
+            Error::Other("something unexpected".to_string()),
+        ];
+
+        // when
+        let retryable = retryable.map(ports::l1::Error::from);
+        let not_retryable = not_retryable.map(ports::l1::Error::from);
+
+        // then
+        assert!(retryable.iter().all(ports::l1::Error::is_retryable));
+        assert!(!not_retryable.iter().any(ports::l1::Error::is_retryable));
+    }
+}
@@ -4,30 +4,94 @@ use std::pin::Pin;
 use async_trait::async_trait;
 use ethers::types::U256;
 use futures::{stream::TryStreamExt, Stream};
-use ports::types::{FuelBlockCommittedOnL1, L1Height};
-use websocket::EthEventStreamer;
+use ports::types::{FuelBlockCommittedOnL1, L1Height, L1Tx, TxState};
+use rpc::EthEventStreamer;
 
+#[cfg(feature = "blobs")]
+mod blob;
+mod contract_state;
 mod error;
 mod metrics;
-mod websocket;
+mod rpc;
+mod signer;
+#[cfg(test)]
+mod test_utils;
 
+pub use contract_state::ContractStateWatcher;
 pub use ethers::types::{Address, Chain};
-pub use websocket::WebsocketClient;
+pub use rpc::RpcClient;
+pub use signer::{L1Signer, SignerError};
 
 #[async_trait]
-impl ports::l1::Contract for WebsocketClient {
-    async fn submit(&self, block: ports::types::FuelBlock) -> ports::l1::Result<()> {
-        self.submit(block).await
+impl ports::l1::Contract for RpcClient {
+    async fn submit(&self, block: ports::types::FuelBlock, nonce: u64) -> ports::l1::Result<L1Tx> {
+        self.submit(block, nonce).await
+    }
+
+    async fn submit_with_blobs(
+        &self,
+        block: ports::types::FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+    ) -> ports::l1::Result<L1Tx> {
+        self.submit_with_blobs(block, data, nonce).await
+    }
+
+    async fn resubmit(
+        &self,
+        block: ports::types::FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> ports::l1::Result<L1Tx> {
+        self.resubmit(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)
+            .await
+    }
+
+    async fn resubmit_with_blobs(
+        &self,
+        block: ports::types::FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> ports::l1::Result<L1Tx> {
+        self.resubmit_with_blobs(
+            block,
+            data,
+            nonce,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas,
+        )
+        .await
     }
 
     fn event_streamer(&self, height: L1Height) -> Box<dyn ports::l1::EventStreamer + Send + Sync> {
         let stream = self.event_streamer(height.into());
         Box::new(stream)
     }
+
+    async fn block_hash_at_commit_height(&self, commit_height: u32) -> ports::l1::Result<[u8; 32]> {
+        self.block_hash_at_commit_height(commit_height).await
+    }
+
+    async fn commit_event(
+        &self,
+        block: ports::types::FuelBlock,
+        from_height: L1Height,
+    ) -> ports::l1::Result<Option<FuelBlockCommittedOnL1>> {
+        self.commit_event(block, from_height.into()).await
+    }
+
+    async fn finalized(&self, block: ports::types::FuelBlock) -> ports::l1::Result<bool> {
+        self.finalized(block).await
+    }
 }
 
 #[async_trait]
-impl ports::l1::Api for WebsocketClient {
+impl ports::l1::Api for RpcClient {
     async fn balance(&self) -> ports::l1::Result<U256> {
         Ok(self.balance().await?)
     }
@@ -37,6 +101,18 @@ impl ports::l1::Api for WebsocketClient {
         let height = L1Height::try_from(block_num)?;
         Ok(height)
     }
+
+    async fn pending_nonce(&self) -> ports::l1::Result<u64> {
+        self.pending_nonce().await
+    }
+
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> ports::l1::Result<TxState> {
+        Ok(self.tx_state(tx_hash).await?)
+    }
+
+    async fn block_hash_at(&self, height: L1Height) -> ports::l1::Result<Option<[u8; 32]>> {
+        Ok(self.block_hash_at(height.into()).await?)
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,213 @@
+use std::{num::NonZeroU32, time::Duration};
+
+use ::metrics::{prometheus::core::Collector, HealthChecker, RegistersMetrics};
+use ethers::types::Address;
+use ports::{
+    l1::Result,
+    types::{FuelBlock, FuelBlockCommittedOnL1, L1Tx, TxState, U256},
+};
+use url::Url;
+
+use crate::signer::L1Signer;
+
+pub use self::event_streamer::EthEventStreamer;
+use self::{
+    failover::FailoverConnection,
+    health_tracking_middleware::{EthApi, HealthTrackingMiddleware},
+    quorum::Quorum,
+};
+
+mod connection;
+mod event_streamer;
+mod failover;
+mod health_tracking_middleware;
+mod quorum;
+mod transport;
+
+#[cfg(test)]
+pub(crate) use self::connection::{PausedFilter, RpcConnection, UnpausedFilter};
+pub(crate) use self::transport::Transport;
+
+#[derive(Clone)]
+pub struct RpcClient {
+    inner: HealthTrackingMiddleware<FailoverConnection>,
+    failover: FailoverConnection,
+    quorum: Option<Quorum>,
+}
+
+impl RpcClient {
+    /// Connects to the first reachable of `urls`, given in order of priority. The others are
+    /// failed over to once the endpoint in use sees `unhealthy_after_n_errors` consecutive
+    /// network errors. The endpoints preferred over the one in use are probed every
+    /// `reprobe_interval`, to switch back to them once they recover.
+    pub async fn connect(
+        urls: &[Url],
+        contract_address: Address,
+        signer: L1Signer,
+        commit_interval: NonZeroU32,
+        logs_block_range: NonZeroU32,
+        unhealthy_after_n_errors: usize,
+        reprobe_interval: Duration,
+    ) -> ports::l1::Result<Self> {
+        let failover = FailoverConnection::connect(
+            urls,
+            contract_address,
+            signer,
+            commit_interval,
+            logs_block_range,
+            unhealthy_after_n_errors,
+            reprobe_interval,
+        )
+        .await?;
+
+        Ok(Self {
+            inner: HealthTrackingMiddleware::new(failover.clone(), unhealthy_after_n_errors),
+            failover,
+            quorum: None,
+        })
+    }
+
+    /// Requires `required` of the endpoints to agree on the latest block number and on every
+    /// commit event before they are handed out.
+    pub fn with_quorum(mut self, required: NonZeroU32) -> ports::l1::Result<Self> {
+        self.quorum = Some(self.failover.quorum(required)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn connection_health_checker(&self) -> HealthChecker {
+        self.inner.connection_health_checker()
+    }
+
+    #[must_use]
+    pub fn endpoint_health_checkers(&self) -> Vec<(String, HealthChecker)> {
+        self.failover.endpoint_health_checkers()
+    }
+
+    /// Tells whether the endpoints recently reached a quorum, `None` if none is required.
+    #[must_use]
+    pub fn quorum_health_checker(&self) -> Option<HealthChecker> {
+        self.quorum.as_ref().map(Quorum::health_checker)
+    }
+
+    pub(crate) fn event_streamer(&self, eth_block_height: u64) -> EthEventStreamer {
+        let streamer = self.inner.event_streamer(eth_block_height);
+        match &self.quorum {
+            Some(quorum) => streamer.confirmed_by(quorum.clone()),
+            None => streamer,
+        }
+    }
+
+    pub(crate) async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx> {
+        Ok(self.inner.submit(block, nonce).await?)
+    }
+
+    pub(crate) async fn submit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+    ) -> Result<L1Tx> {
+        Ok(self.inner.submit_with_blobs(block, data, nonce).await?)
+    }
+
+    pub(crate) async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx> {
+        Ok(self
+            .inner
+            .resubmit(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)
+            .await?)
+    }
+
+    pub(crate) async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx> {
+        Ok(self
+            .inner
+            .resubmit_with_blobs(
+                block,
+                data,
+                nonce,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            )
+            .await?)
+    }
+
+    pub(crate) async fn get_block_number(&self) -> Result<u64> {
+        match &self.quorum {
+            Some(quorum) => Ok(quorum.block_number().await?),
+            None => Ok(self.inner.get_block_number().await?),
+        }
+    }
+
+    pub(crate) async fn balance(&self) -> Result<U256> {
+        Ok(self.inner.balance().await?)
+    }
+
+    pub(crate) async fn pending_nonce(&self) -> Result<u64> {
+        Ok(self.inner.pending_nonce().await?)
+    }
+
+    pub(crate) async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState> {
+        Ok(self.inner.tx_state(tx_hash).await?)
+    }
+
+    pub(crate) async fn block_hash_at(&self, height: u64) -> Result<Option<[u8; 32]>> {
+        Ok(self.inner.block_hash_at(height).await?)
+    }
+
+    pub async fn finalized(&self, block: FuelBlock) -> Result<bool> {
+        Ok(self.inner.finalized(block).await?)
+    }
+
+    pub async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]> {
+        Ok(self
+            .inner
+            .block_hash_at_commit_height(commit_height)
+            .await?)
+    }
+
+    pub(crate) async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_block: u64,
+    ) -> Result<Option<FuelBlockCommittedOnL1>> {
+        Ok(self.inner.commit_event(block, from_block).await?)
+    }
+
+    pub(crate) async fn paused(&self) -> Result<bool> {
+        Ok(self.inner.paused().await?)
+    }
+
+    pub(crate) async fn pause_changes(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Option<bool>> {
+        Ok(self.inner.pause_changes(from_block, to_block).await?)
+    }
+}
+
+impl RegistersMetrics for RpcClient {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        let mut metrics = self.inner.metrics();
+        metrics.extend(self.failover.metrics());
+        if let Some(quorum) = &self.quorum {
+            metrics.extend(quorum.metrics());
+        }
+        metrics
+    }
+}
@@ -0,0 +1,630 @@
+use std::{num::NonZeroU32, sync::Arc};
+
+use ethers::{
+    prelude::{abigen, ContractCall, ContractError, SignerMiddleware},
+    providers::{Middleware, Provider},
+    signers::Signer,
+    types::{transaction::eip2718::TypedTransaction, Address, BlockNumber, H160, H256, U256, U64},
+};
+use ports::types::{FuelBlock, FuelBlockCommittedOnL1, L1Tx, TxState};
+use serde_json::Value;
+use url::Url;
+
+use super::{
+    event_streamer::{committed_on_l1, decode_log, was_removed, EthEventStreamer},
+    health_tracking_middleware::EthApi,
+    transport::Transport,
+};
+use crate::{
+    error::{Error, Result},
+    signer::L1Signer,
+};
+
+abigen!(
+    FUEL_STATE_CONTRACT,
+    r#"[
+        function commit(bytes32 blockHash, uint256 commitHeight) external whenNotPaused
+        event CommitSubmitted(uint256 indexed commitHeight, bytes32 blockHash)
+        function finalized(bytes32 blockHash, uint256 blockHeight) external view whenNotPaused returns (bool)
+        function blockHashAtCommit(uint256 commitHeight) external view returns (bytes32)
+        function paused() external view returns (bool)
+        event Paused(address account)
+        event Unpaused(address account)
+    ]"#,
+);
+
+type CommitCall = ContractCall<SignerMiddleware<Provider<Transport>, L1Signer>, ()>;
+
+#[derive(Clone)]
+pub struct RpcConnection {
+    provider: Provider<Transport>,
+    contract: FUEL_STATE_CONTRACT<SignerMiddleware<Provider<Transport>, L1Signer>>,
+    commit_interval: NonZeroU32,
+    logs_block_range: NonZeroU32,
+    address: H160,
+}
+
+#[async_trait::async_trait]
+impl EthApi for RpcConnection {
+    async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx> {
+        let commit_height = Self::calculate_commit_height(block.height, self.commit_interval);
+        let mut contract_call = self.contract.commit(block.hash, commit_height);
+        contract_call.tx.set_nonce(nonce);
+
+        self.send(contract_call).await
+    }
+
+    async fn submit_with_blobs(&self, block: FuelBlock, data: Vec<u8>, nonce: u64) -> Result<L1Tx> {
+        #[cfg(feature = "blobs")]
+        {
+            let commit_height = Self::calculate_commit_height(block.height, self.commit_interval);
+            let mut contract_call = self.contract.commit(block.hash, commit_height);
+            contract_call.tx.set_nonce(nonce);
+
+            self.send_with_blobs(contract_call, &data, None).await
+        }
+
+        #[cfg(not(feature = "blobs"))]
+        {
+            let _ = (block, data, nonce);
+            Err(Error::Other(
+                "the committer was built without the `blobs` feature".to_string(),
+            ))
+        }
+    }
+
+    async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx> {
+        let contract_call =
+            self.replacement_call(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)?;
+
+        self.send(contract_call).await
+    }
+
+    async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx> {
+        #[cfg(feature = "blobs")]
+        {
+            let contract_call =
+                self.replacement_call(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)?;
+
+            self.send_with_blobs(contract_call, &data, Some(max_fee_per_blob_gas.into()))
+                .await
+        }
+
+        #[cfg(not(feature = "blobs"))]
+        {
+            let _ = (
+                block,
+                data,
+                nonce,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            );
+            Err(Error::Other(
+                "the committer was built without the `blobs` feature".to_string(),
+            ))
+        }
+    }
+
+    async fn pending_nonce(&self) -> Result<u64> {
+        let nonce = self
+            .provider
+            .get_transaction_count(self.address, Some(BlockNumber::Pending.into()))
+            .await?;
+
+        u64::try_from(nonce)
+            .map_err(|_| Error::Other(format!("pending nonce of {nonce} doesn't fit into a u64")))
+    }
+
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState> {
+        let tx_hash = H256(tx_hash);
+
+        if let Some(receipt) = self.provider.get_transaction_receipt(tx_hash).await? {
+            let state = if receipt.status == Some(U64::one()) {
+                TxState::Mined
+            } else {
+                TxState::Reverted
+            };
+            return Ok(state);
+        }
+
+        let state = if self.provider.get_transaction(tx_hash).await?.is_some() {
+            TxState::Pending
+        } else {
+            TxState::Dropped
+        };
+
+        Ok(state)
+    }
+
+    async fn block_hash_at(&self, height: u64) -> Result<Option<[u8; 32]>> {
+        let block = self.provider.get_block(height).await?;
+        Ok(block.and_then(|block| block.hash).map(|hash| hash.0))
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        block_number(&self.provider).await
+    }
+
+    async fn balance(&self) -> Result<U256> {
+        let address = self.address;
+        Ok(self.provider.get_balance(address, None).await?)
+    }
+
+    fn event_streamer(&self, eth_block_height: u64) -> EthEventStreamer {
+        let events = self
+            .contract
+            .event::<CommitSubmittedFilter>()
+            .from_block(eth_block_height);
+
+        EthEventStreamer::new(events, self.provider.clone(), self.logs_block_range)
+    }
+
+    async fn finalized(&self, block: FuelBlock) -> Result<bool> {
+        Ok(self
+            .contract
+            .finalized(block.hash, block.height.into())
+            .call()
+            .await?)
+    }
+
+    async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]> {
+        Ok(self
+            .contract
+            .block_hash_at_commit(commit_height.into())
+            .call()
+            .await?)
+    }
+
+    async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_block: u64,
+    ) -> Result<Option<FuelBlockCommittedOnL1>> {
+        let commit_height = Self::calculate_commit_height(block.height, self.commit_interval);
+        let events = self
+            .contract
+            .event::<CommitSubmittedFilter>()
+            .topic1(commit_height);
+        let to_block = block_number(&self.provider).await?;
+
+        let range = u64::from(self.logs_block_range.get());
+        let mut start = from_block;
+        while start <= to_block {
+            let end = to_block.min(start.saturating_add(range - 1));
+
+            let filter = events.filter.clone().from_block(start).to_block(end);
+            for log in self.provider.get_logs(&filter).await? {
+                if was_removed(&log) {
+                    continue;
+                }
+
+                let (event, meta) = decode_log(log)?;
+                if event.block_hash == block.hash {
+                    return committed_on_l1(event, &meta).map(Some);
+                }
+            }
+
+            start = end + 1;
+        }
+
+        Ok(None)
+    }
+
+    async fn paused(&self) -> Result<bool> {
+        Ok(self.contract.paused().call().await?)
+    }
+
+    async fn pause_changes(&self, from_block: u64, to_block: u64) -> Result<Option<bool>> {
+        let mut paused = None;
+
+        let range = u64::from(self.logs_block_range.get());
+        let mut start = from_block;
+        while start <= to_block {
+            let end = to_block.min(start.saturating_add(range - 1));
+
+            let events = self
+                .contract
+                .events()
+                .from_block(start)
+                .to_block(end)
+                .query()
+                .await?;
+
+            paused = events
+                .into_iter()
+                .filter_map(|event| match event {
+                    FUEL_STATE_CONTRACTEvents::PausedFilter(_) => Some(true),
+                    FUEL_STATE_CONTRACTEvents::UnpausedFilter(_) => Some(false),
+                    FUEL_STATE_CONTRACTEvents::CommitSubmittedFilter(_) => None,
+                })
+                .next_back()
+                .or(paused);
+
+            start = end + 1;
+        }
+
+        Ok(paused)
+    }
+}
+
+impl RpcConnection {
+    pub async fn connect(
+        url: &Url,
+        contract_address: Address,
+        signer: L1Signer,
+        commit_interval: NonZeroU32,
+        logs_block_range: NonZeroU32,
+    ) -> Result<Self> {
+        let provider = Provider::new(Transport::connect(url).await?);
+
+        let address = signer.address();
+
+        let signer = SignerMiddleware::new(provider.clone(), signer);
+
+        let contract_address = Address::from_slice(contract_address.as_ref());
+        let contract = FUEL_STATE_CONTRACT::new(contract_address, Arc::new(signer));
+
+        Ok(Self {
+            provider,
+            contract,
+            commit_interval,
+            logs_block_range,
+            address,
+        })
+    }
+
+    /// The commit of `block` reusing `nonce` with the given fees, so that it replaces any
+    /// transaction still pending with that nonce.
+    fn replacement_call(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<CommitCall> {
+        let commit_height = Self::calculate_commit_height(block.height, self.commit_interval);
+        let mut contract_call = self.contract.commit(block.hash, commit_height);
+
+        let tx = contract_call.tx.as_eip1559_mut().ok_or_else(|| {
+            Error::Other("only EIP-1559 commit transactions can be replaced".to_string())
+        })?;
+        tx.nonce = Some(nonce.into());
+        tx.max_fee_per_gas = Some(max_fee_per_gas.into());
+        tx.max_priority_fee_per_gas = Some(max_priority_fee_per_gas.into());
+
+        Ok(contract_call)
+    }
+
+    async fn send(&self, mut contract_call: CommitCall) -> Result<L1Tx> {
+        let (nonce, max_fee_per_gas, max_priority_fee_per_gas) =
+            self.prepare(&mut contract_call).await?;
+
+        let tx = contract_call.send().await?;
+
+        tracing::info!("tx: {} submitted", tx.tx_hash());
+
+        Ok(L1Tx {
+            hash: tx.tx_hash().0,
+            nonce,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas: None,
+        })
+    }
+
+    /// Sends the commit call in an EIP-4844 tx carrying `data` in its blobs. The blob fee is
+    /// derived from the current blob base fee unless given.
+    #[cfg(feature = "blobs")]
+    async fn send_with_blobs(
+        &self,
+        mut contract_call: CommitCall,
+        data: &[u8],
+        max_fee_per_blob_gas: Option<U256>,
+    ) -> Result<L1Tx> {
+        use crate::blob::{BlobSidecar, BlobTx};
+
+        let (nonce, max_fee_per_gas, max_priority_fee_per_gas) =
+            self.prepare(&mut contract_call).await?;
+
+        let max_fee_per_blob_gas = match max_fee_per_blob_gas {
+            Some(fee) => fee,
+            None => {
+                let blob_base_fee = self
+                    .provider
+                    .request::<Value, U256>("eth_blobBaseFee", Value::Array(vec![]))
+                    .await?;
+                // leaves room for the blob base fee to rise while the tx is pending
+                blob_base_fee.saturating_mul(2.into())
+            }
+        };
+        let recorded_blob_fee = u64::try_from(max_fee_per_blob_gas).map_err(|_| {
+            Error::Other(format!(
+                "max fee per blob gas of {max_fee_per_blob_gas} doesn't fit into a u64"
+            ))
+        })?;
+
+        let client = self.contract.client();
+        let signer = client.signer();
+        let tx = BlobTx {
+            chain_id: signer.chain_id(),
+            nonce,
+            max_priority_fee_per_gas,
+            max_fee_per_gas,
+            gas: contract_call
+                .tx
+                .gas()
+                .copied()
+                .ok_or_else(|| Error::Other("tx is missing the gas limit".to_string()))?,
+            to: self.contract.address(),
+            data: contract_call.tx.data().cloned().unwrap_or_default(),
+            max_fee_per_blob_gas,
+            sidecar: BlobSidecar::new(data)?,
+        };
+
+        let signature = signer
+            .sign_hash(tx.sighash())
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let (hash, raw) = tx.encode_signed(&signature);
+
+        self.provider.send_raw_transaction(raw).await?;
+
+        tracing::info!("blob tx: {hash:?} submitted");
+
+        Ok(L1Tx {
+            hash: hash.0,
+            nonce,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas: Some(recorded_blob_fee),
+        })
+    }
+
+    /// Checks the call would go through and fills in the tx, returning its nonce and fees.
+    async fn prepare(&self, contract_call: &mut CommitCall) -> Result<(u64, u64, u64)> {
+        // Simulating the call first surfaces why the contract would reject it, without paying for
+        // a reverting tx.
+        contract_call.call().await?;
+
+        // Filling the tx upfront lets us know the nonce and fees it is going to be sent with. The
+        // gas estimation involved reverts just like the simulation would.
+        self.contract
+            .client()
+            .fill_transaction(&mut contract_call.tx, None)
+            .await
+            .map_err(ContractError::from_middleware_error)?;
+
+        Self::nonce_and_fees(&contract_call.tx)
+    }
+
+    fn nonce_and_fees(tx: &TypedTransaction) -> Result<(u64, u64, u64)> {
+        let to_u64 = |name: &str, value: Option<U256>| {
+            let value = value.ok_or_else(|| Error::Other(format!("tx is missing the {name}")))?;
+            u64::try_from(value)
+                .map_err(|_| Error::Other(format!("tx {name} of {value} doesn't fit into a u64")))
+        };
+
+        let nonce = to_u64("nonce", tx.nonce().copied())?;
+        let (max_fee_per_gas, max_priority_fee_per_gas) = match tx {
+            TypedTransaction::Eip1559(tx) => (
+                to_u64("max fee per gas", tx.max_fee_per_gas)?,
+                to_u64("max priority fee per gas", tx.max_priority_fee_per_gas)?,
+            ),
+            _ => {
+                let gas_price = to_u64("gas price", tx.gas_price())?;
+                (gas_price, gas_price)
+            }
+        };
+
+        Ok((nonce, max_fee_per_gas, max_priority_fee_per_gas))
+    }
+
+    /// The commit events emitted within the block with the given hash.
+    pub(crate) async fn commits_in_block(
+        &self,
+        block_hash: [u8; 32],
+    ) -> Result<Vec<FuelBlockCommittedOnL1>> {
+        let filter = self
+            .contract
+            .event::<CommitSubmittedFilter>()
+            .filter
+            .at_block_hash(H256(block_hash));
+
+        self.provider
+            .get_logs(&filter)
+            .await?
+            .into_iter()
+            .map(|log| {
+                let (event, meta) = decode_log(log)?;
+                committed_on_l1(event, &meta)
+            })
+            .collect()
+    }
+
+    pub(crate) fn calculate_commit_height(block_height: u32, commit_interval: NonZeroU32) -> U256 {
+        (block_height / commit_interval).into()
+    }
+
+    async fn _balance(&self, address: H160) -> Result<U256> {
+        Ok(self.provider.get_balance(address, None).await?)
+    }
+}
+
+pub(crate) async fn block_number(provider: &Provider<Transport>) -> Result<u64> {
+    // if provider.get_block_number is used the outgoing JSON RPC request would have the
+    // 'params' field set as `params: null`. This is accepted by Anvil but rejected by hardhat.
+    // By passing a preconstructed serde_json Value::Array it will cause params to be defined
+    // as `params: []` which is acceptable by both Anvil and Hardhat.
+    let response = provider
+        .request::<Value, U64>("eth_blockNumber", Value::Array(vec![]))
+        .await?;
+    Ok(response.as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_correctly_the_commit_height() {
+        assert_eq!(
+            RpcConnection::calculate_commit_height(10, 3.try_into().unwrap()),
+            3.into()
+        );
+    }
+
+    #[tokio::test]
+    async fn finds_the_commit_event_of_a_block() {
+        use ethers::contract::EthEvent;
+        use serde_json::json;
+
+        use crate::test_utils::{given_connection, spawn_rpc_server};
+
+        // given
+        let block = FuelBlock {
+            hash: [1; 32],
+            height: 10,
+        };
+        let included_in = H256::random();
+        let given_log = |fuel_block_hash: H256, block_hash: H256, removed: bool| {
+            json!({
+                "address": H160::zero(),
+                "topics": [CommitSubmittedFilter::signature(), H256::from_low_u64_be(10)],
+                "data": fuel_block_hash,
+                "blockNumber": "0x4",
+                "blockHash": block_hash,
+                "transactionHash": H256::random(),
+                "transactionIndex": "0x0",
+                "logIndex": "0x0",
+                "removed": removed,
+            })
+        };
+        let logs = json!([
+            given_log(H256::random(), H256::random(), false),
+            given_log(H256(block.hash), H256::random(), true),
+            given_log(H256(block.hash), included_in, false),
+        ]);
+        let url = spawn_rpc_server(move |method, params| match method {
+            "eth_blockNumber" => json!("0x5"),
+            "eth_getLogs" if params[0]["fromBlock"] == "0x3" => logs.clone(),
+            _ => json!([]),
+        })
+        .await;
+
+        let connection = given_connection(&url).await;
+
+        // when
+        let event = connection.commit_event(block, 3).await.unwrap().unwrap();
+
+        // then
+        assert_eq!(event.fuel_block_hash, block.hash);
+        assert_eq!(event.included_in.hash, included_in.0);
+    }
+
+    #[cfg(feature = "blobs")]
+    #[tokio::test]
+    async fn sends_the_commit_in_a_blob_tx() {
+        use std::sync::Mutex;
+
+        use ethers::utils::rlp::Rlp;
+        use serde_json::json;
+
+        use crate::test_utils::{given_connection, spawn_rpc_server};
+
+        // given
+        let sent = Arc::new(Mutex::new(None));
+        let url = spawn_rpc_server({
+            let sent = Arc::clone(&sent);
+            move |method, params| match method {
+                "eth_call" => json!("0x"),
+                "eth_estimateGas" => json!("0x5208"),
+                "eth_getBlockByNumber" => given_latest_block(),
+                "eth_feeHistory" => json!({
+                    "oldestBlock": "0x1",
+                    "baseFeePerGas": ["0x7", "0x7"],
+                    "gasUsedRatio": [0.5],
+                    "reward": [["0x1"]],
+                }),
+                "eth_blobBaseFee" => json!("0x1"),
+                "eth_sendRawTransaction" => {
+                    *sent.lock().unwrap() = params[0].as_str().map(str::to_string);
+                    json!(format!("0x{}", "11".repeat(32)))
+                }
+                _ => Value::Null,
+            }
+        })
+        .await;
+
+        let connection = given_connection(&url).await;
+
+        let block = FuelBlock {
+            hash: [1; 32],
+            height: 10,
+        };
+
+        // when
+        let tx = connection
+            .submit_with_blobs(block, vec![1, 2, 3], 5)
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(tx.nonce, 5);
+        assert_eq!(tx.max_fee_per_blob_gas, Some(2));
+
+        let raw: ethers::types::Bytes = sent.lock().unwrap().clone().unwrap().parse().unwrap();
+        assert_eq!(raw[0], 0x03);
+        let network = Rlp::new(&raw[1..]);
+        assert_eq!(network.item_count().unwrap(), 4);
+        assert_eq!(network.at(1).unwrap().item_count().unwrap(), 1);
+        assert_eq!(
+            H256(tx.hash),
+            H256(ethers::utils::keccak256(
+                [&[0x03][..], network.at(0).unwrap().as_raw()].concat()
+            ))
+        );
+    }
+
+    #[cfg(feature = "blobs")]
+    fn given_latest_block() -> Value {
+        let zero_hash = format!("0x{}", "00".repeat(32));
+        serde_json::json!({
+            "hash": zero_hash,
+            "parentHash": zero_hash,
+            "sha3Uncles": zero_hash,
+            "miner": format!("0x{}", "00".repeat(20)),
+            "stateRoot": zero_hash,
+            "transactionsRoot": zero_hash,
+            "receiptsRoot": zero_hash,
+            "number": "0x1",
+            "gasUsed": "0x0",
+            "gasLimit": "0x1c9c380",
+            "extraData": "0x",
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "timestamp": "0x1",
+            "difficulty": "0x0",
+            "totalDifficulty": "0x0",
+            "sealFields": [],
+            "uncles": [],
+            "transactions": [],
+            "size": "0x0",
+            "mixHash": zero_hash,
+            "nonce": "0x0000000000000000",
+            "baseFeePerGas": "0x7",
+        })
+    }
+}
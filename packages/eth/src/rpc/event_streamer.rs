@@ -0,0 +1,376 @@
+use std::{num::NonZeroU32, pin::Pin, sync::Arc};
+
+use ethers::{
+    contract::{EthLogDecode, LogMeta},
+    prelude::{Event, SignerMiddleware},
+    providers::{Middleware, Provider},
+    types::{FilterBlockOption, Log},
+};
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
+use ports::types::{FuelBlockCommittedOnL1, L1Block, L1Height};
+use tokio::sync::watch;
+
+use super::{
+    connection::{block_number, CommitSubmittedFilter},
+    failover::ActiveConnection,
+    quorum::Quorum,
+    transport::Transport,
+};
+use crate::{
+    error::{Error, Result},
+    signer::L1Signer,
+};
+
+type EthStreamInitializer = Event<
+    Arc<SignerMiddleware<Provider<Transport>, L1Signer>>,
+    SignerMiddleware<Provider<Transport>, L1Signer>,
+    CommitSubmittedFilter,
+>;
+
+type CommitEventStream<'a> =
+    Pin<Box<dyn Stream<Item = Result<(CommitSubmittedFilter, LogMeta)>> + Send + 'a>>;
+
+pub struct EthEventStreamer {
+    events: EthStreamInitializer,
+    provider: Provider<Transport>,
+    // upper bound on the blocks covered by a single `eth_getLogs` request, providers tend to
+    // reject larger ranges
+    logs_block_range: u64,
+    failover: Option<watch::Receiver<ActiveConnection>>,
+    quorum: Option<Quorum>,
+}
+
+impl EthEventStreamer {
+    pub fn new(
+        events: EthStreamInitializer,
+        provider: Provider<Transport>,
+        logs_block_range: NonZeroU32,
+    ) -> Self {
+        Self {
+            events,
+            provider,
+            logs_block_range: logs_block_range.get().into(),
+            failover: None,
+            quorum: None,
+        }
+    }
+
+    /// Only lets through the events that a quorum of the rpc endpoints agrees on. The stream
+    /// ends right after the first event lacking one.
+    pub(crate) fn confirmed_by(mut self, quorum: Quorum) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Ends the stream once `failover` sees a change of the active endpoint, leaving it to the
+    /// caller to establish a new stream against the endpoint that took over.
+    pub(crate) fn until_failover(mut self, failover: watch::Receiver<ActiveConnection>) -> Self {
+        self.failover = Some(failover);
+        self
+    }
+
+    pub(crate) async fn establish_stream(
+        &self,
+    ) -> Result<impl Stream<Item = Result<FuelBlockCommittedOnL1>> + Send + '_> {
+        let events = if self.provider.as_ref().supports_subscriptions() {
+            self.catch_up_and_subscribe().await?
+        } else {
+            self.poll_logs()
+        };
+
+        let quorum = self.quorum.clone();
+        let stream = events
+            .and_then(|(event, meta)| async move { committed_on_l1(event, &meta) })
+            .and_then(move |committed| {
+                let quorum = quorum.clone();
+                async move {
+                    match quorum {
+                        Some(quorum) if !quorum.confirms(&committed).await => Err(Error::NoQuorum(
+                            format!("{committed:?} wasn't confirmed by enough rpc endpoints"),
+                        )),
+                        _ => Ok(committed),
+                    }
+                }
+            })
+            // the stream is established anew later on, by when lagging endpoints might have caught up
+            .scan(false, |no_quorum, event| {
+                if *no_quorum {
+                    return future::ready(None);
+                }
+                *no_quorum = matches!(event, Err(Error::NoQuorum(_)));
+                future::ready(Some(event))
+            });
+
+        let mut failover = self.failover.clone();
+        let failed_over = async move {
+            match failover.as_mut() {
+                Some(failover) => {
+                    let _ = failover.changed().await;
+                }
+                None => future::pending().await,
+            }
+        };
+
+        Ok(stream.take_until(failed_over))
+    }
+
+    /// Catches up with `eth_getLogs` on the events emitted since the starting block before handing
+    /// over to the subscription. Subscribing first makes sure no event gets lost in between,
+    /// the events it delivers that were already caught up on are skipped.
+    async fn catch_up_and_subscribe(&self) -> Result<CommitEventStream<'_>> {
+        let live = self.subscribe().await?;
+        let head = block_number(&self.provider).await?;
+
+        let live =
+            live.try_filter(move |(_, meta)| future::ready(meta.block_number.as_u64() > head));
+
+        Ok(self
+            .logs_until(self.starting_block(), head)
+            .chain(live)
+            .boxed())
+    }
+
+    async fn subscribe(&self) -> Result<CommitEventStream<'_>> {
+        // not all nodes replay past logs for subscriptions, those are caught up on separately
+        let mut filter = self.events.filter.clone();
+        filter.block_option = FilterBlockOption::default();
+
+        let logs = self.provider.subscribe_logs(&filter).await?;
+        Ok(logs
+            .filter(|log| future::ready(!was_removed(log)))
+            .map(decode_log)
+            .boxed())
+    }
+
+    /// Fetches the logs up to and including `to_block` in ranges of at most `logs_block_range`
+    /// blocks.
+    fn logs_until(&self, from_block: u64, to_block: u64) -> CommitEventStream<'_> {
+        stream::try_unfold(from_block, move |from_block| async move {
+            if from_block > to_block {
+                return Ok(None);
+            }
+
+            let range_end = to_block.min(from_block + self.logs_block_range - 1);
+            let events = self.logs(from_block, range_end).await?;
+
+            Ok::<_, Error>(Some((stream::iter(events), range_end + 1)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Fetches the logs with `eth_getLogs` in ranges of at most `logs_block_range` blocks,
+    /// waiting for new blocks once it caught up with the chain.
+    fn poll_logs(&self) -> CommitEventStream<'_> {
+        stream::try_unfold(self.starting_block(), move |from_block| async move {
+            let to_block = self.wait_for_block(from_block).await?;
+            let events = self.logs(from_block, to_block).await?;
+
+            Ok::<_, Error>(Some((stream::iter(events), to_block + 1)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    async fn logs(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Result<(CommitSubmittedFilter, LogMeta)>>> {
+        let filter = self
+            .events
+            .filter
+            .clone()
+            .from_block(from_block)
+            .to_block(to_block);
+
+        Ok(self
+            .provider
+            .get_logs(&filter)
+            .await?
+            .into_iter()
+            .filter(|log| !was_removed(log))
+            .map(decode_log)
+            .collect())
+    }
+
+    fn starting_block(&self) -> u64 {
+        self.events
+            .filter
+            .get_from_block()
+            .map_or(0, |block| block.as_u64())
+    }
+
+    /// Waits until `from_block` is mined, returns the end of the range to fetch logs for.
+    async fn wait_for_block(&self, from_block: u64) -> Result<u64> {
+        loop {
+            let latest_block = block_number(&self.provider).await?;
+            if latest_block >= from_block {
+                return Ok(latest_block.min(from_block + self.logs_block_range - 1));
+            }
+
+            tokio::time::sleep(self.provider.get_interval()).await;
+        }
+    }
+}
+
+/// Whether the log belongs to a block that got reorged out, nodes deliver such logs again flagged
+/// as removed.
+pub(super) fn was_removed(log: &Log) -> bool {
+    log.removed == Some(true)
+}
+
+pub(super) fn decode_log(log: Log) -> Result<(CommitSubmittedFilter, LogMeta)> {
+    let meta = LogMeta::from(&log);
+    let event =
+        CommitSubmittedFilter::decode_log(&log.into()).map_err(|e| Error::Other(e.to_string()))?;
+    Ok((event, meta))
+}
+
+pub(super) fn committed_on_l1(
+    event: CommitSubmittedFilter,
+    meta: &LogMeta,
+) -> Result<FuelBlockCommittedOnL1> {
+    let height =
+        L1Height::try_from(meta.block_number.as_u64()).map_err(|e| Error::Other(e.to_string()))?;
+    Ok(FuelBlockCommittedOnL1 {
+        fuel_block_hash: event.block_hash,
+        commit_height: event.commit_height,
+        included_in: L1Block {
+            height,
+            hash: meta.block_hash.0,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::{
+        contract::EthEvent,
+        types::{H160, H256, U256},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        rpc::health_tracking_middleware::EthApi,
+        test_utils::{given_connection, spawn_rpc_server},
+    };
+
+    #[tokio::test]
+    async fn polls_for_commit_events_over_http() {
+        // given
+        let fuel_block_hash = H256::random();
+        let l1_block_hash = H256::random();
+        let log = json!({
+            "address": H160::zero(),
+            "topics": [CommitSubmittedFilter::signature(), H256::from_low_u64_be(7)],
+            "data": fuel_block_hash,
+            "blockNumber": "0x3",
+            "blockHash": l1_block_hash,
+            "transactionHash": H256::random(),
+            "transactionIndex": "0x0",
+            "logIndex": "0x0",
+            "removed": false,
+        });
+        let url = spawn_rpc_server(move |method, params| match method {
+            "eth_blockNumber" => json!("0x5"),
+            "eth_getLogs" if params[0]["fromBlock"] == "0x0" => json!([log]),
+            _ => json!([]),
+        })
+        .await;
+
+        let connection = given_connection(&url).await;
+        let streamer = connection.event_streamer(0);
+
+        // when
+        let event = Box::pin(streamer.establish_stream().await.unwrap())
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+
+        // then
+        assert_eq!(event.fuel_block_hash, fuel_block_hash.0);
+        assert_eq!(event.commit_height, U256::from(7));
+        assert!(
+            event.included_in
+                == L1Block {
+                    height: 3u32.into(),
+                    hash: l1_block_hash.0,
+                }
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_logs_removed_by_a_reorg() {
+        // given
+        let given_log = |fuel_block_hash: H256, removed: bool| {
+            json!({
+                "address": H160::zero(),
+                "topics": [CommitSubmittedFilter::signature(), H256::from_low_u64_be(7)],
+                "data": fuel_block_hash,
+                "blockNumber": "0x3",
+                "blockHash": H256::random(),
+                "transactionHash": H256::random(),
+                "transactionIndex": "0x0",
+                "logIndex": "0x0",
+                "removed": removed,
+            })
+        };
+        let removed_hash = H256::random();
+        let kept_hash = H256::random();
+        let logs = json!([given_log(removed_hash, true), given_log(kept_hash, false)]);
+        let url = spawn_rpc_server(move |method, params| match method {
+            "eth_blockNumber" => json!("0x5"),
+            "eth_getLogs" if params[0]["fromBlock"] == "0x0" => logs.clone(),
+            _ => json!([]),
+        })
+        .await;
+
+        let connection = given_connection(&url).await;
+        let streamer = connection.event_streamer(0);
+
+        // when
+        let event = Box::pin(streamer.establish_stream().await.unwrap())
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+
+        // then
+        assert_eq!(event.fuel_block_hash, kept_hash.0);
+    }
+
+    #[tokio::test]
+    async fn catches_up_in_ranges_of_the_configured_size() {
+        // given
+        let requested_ranges = Arc::new(std::sync::Mutex::new(vec![]));
+        let url = spawn_rpc_server({
+            let requested_ranges = Arc::clone(&requested_ranges);
+            move |_, params| {
+                let range = (
+                    params[0]["fromBlock"].as_str().unwrap().to_string(),
+                    params[0]["toBlock"].as_str().unwrap().to_string(),
+                );
+                requested_ranges.lock().unwrap().push(range);
+                json!([])
+            }
+        })
+        .await;
+
+        let connection = given_connection(&url).await;
+        let streamer = connection.event_streamer(500);
+
+        // when
+        let events = streamer.logs_until(500, 2600).collect::<Vec<_>>().await;
+
+        // then
+        assert!(events.is_empty());
+        assert_eq!(
+            *requested_ranges.lock().unwrap(),
+            [("0x1f4", "0x5db"), ("0x5dc", "0x9c3"), ("0x9c4", "0xa28")]
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+        );
+    }
+}
@@ -0,0 +1,585 @@
+use std::{
+    num::NonZeroU32,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use ::metrics::{
+    endpoint_name,
+    prometheus::{core::Collector, IntCounter, IntGaugeVec, Opts},
+    ConnectionHealthTracker, HealthCheck, HealthChecker, RegistersMetrics,
+};
+use ethers::types::Address;
+use ports::types::{FuelBlock, FuelBlockCommittedOnL1, L1Tx, TxState, U256};
+use tokio::sync::{watch, Mutex};
+use tracing::{error, info, warn};
+use url::Url;
+
+use super::{
+    connection::RpcConnection, event_streamer::EthEventStreamer,
+    health_tracking_middleware::EthApi, quorum::Quorum,
+};
+use crate::{
+    error::{Error, Result},
+    signer::L1Signer,
+};
+
+/// An RPC endpoint together with the health of the requests sent to it.
+struct Endpoint {
+    url: Url,
+    name: String,
+    health_tracker: ConnectionHealthTracker,
+}
+
+#[derive(Clone)]
+pub(crate) struct ActiveConnection {
+    index: usize,
+    connection: RpcConnection,
+}
+
+/// Sends the requests to one endpoint at a time, endpoints being listed in order of priority.
+/// Once the active endpoint sees enough consecutive network errors the highest priority endpoint
+/// that can be connected to takes over. Endpoints of higher priority than the active one are
+/// probed every `reprobe_interval`, the first one to answer taking over again.
+#[derive(Clone)]
+pub struct FailoverConnection {
+    endpoints: Arc<[Endpoint]>,
+    // event streams subscribe to it so that they end, and get re-established, on a switch
+    active: Arc<watch::Sender<ActiveConnection>>,
+    // held while switching endpoints, guards when the last switch or probe happened
+    last_switch: Arc<Mutex<Instant>>,
+    reprobe_interval: Duration,
+    connector: Connector,
+    metrics: Metrics,
+}
+
+impl FailoverConnection {
+    pub async fn connect(
+        urls: &[Url],
+        contract_address: Address,
+        signer: L1Signer,
+        commit_interval: NonZeroU32,
+        logs_block_range: NonZeroU32,
+        unhealthy_after_n_errors: usize,
+        reprobe_interval: Duration,
+    ) -> Result<Self> {
+        let endpoints: Arc<[Endpoint]> = urls
+            .iter()
+            .map(|url| Endpoint {
+                url: url.clone(),
+                name: endpoint_name(url),
+                health_tracker: ConnectionHealthTracker::new(unhealthy_after_n_errors),
+            })
+            .collect();
+
+        let metrics = Metrics::default();
+        for endpoint in endpoints.iter() {
+            metrics.note_health(endpoint);
+        }
+
+        let connector = Connector::new(contract_address, signer, commit_interval, logs_block_range);
+        let active = connector
+            .connect_first_available(&endpoints, 0..endpoints.len(), &metrics)
+            .await?;
+
+        Ok(Self {
+            endpoints,
+            active: Arc::new(watch::channel(active).0),
+            last_switch: Arc::new(Mutex::new(Instant::now())),
+            reprobe_interval,
+            connector,
+            metrics,
+        })
+    }
+
+    /// Health of every endpoint, named by their scheme, host and port so that no api keys leak.
+    #[must_use]
+    pub fn endpoint_health_checkers(&self) -> Vec<(String, HealthChecker)> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| (endpoint.name.clone(), endpoint.health_tracker.tracker()))
+            .collect()
+    }
+
+    /// Requires `required` of the endpoints to agree on reads guarded by the returned quorum.
+    pub(super) fn quorum(&self, required: NonZeroU32) -> Result<Quorum> {
+        let endpoints = self
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint.name.clone(), endpoint.url.clone()))
+            .collect();
+
+        Quorum::new(endpoints, self.connector.clone(), required)
+    }
+
+    fn active(&self) -> ActiveConnection {
+        self.active.borrow().clone()
+    }
+
+    async fn track<K>(&self, index: usize, response: Result<K>) -> Result<K> {
+        let endpoint = &self.endpoints[index];
+
+        match &response {
+            Ok(_) => endpoint.health_tracker.note_success(),
+            Err(err) if err.is_network() => endpoint.health_tracker.note_failure(),
+            Err(_) => return response,
+        }
+        self.metrics.note_health(endpoint);
+
+        if !endpoint.health_tracker.healthy() {
+            self.fail_over(index).await;
+        } else if index != 0 {
+            self.fail_back_if_due(index).await;
+        }
+
+        response
+    }
+
+    async fn fail_over(&self, failed: usize) {
+        let mut last_switch = self.last_switch.lock().await;
+
+        // a concurrent request might have failed over already
+        if self.active.borrow().index != failed {
+            return;
+        }
+
+        // the failed endpoint is tried last, reconnecting still helps if a websocket got dropped
+        let candidates = (0..self.endpoints.len())
+            .filter(|index| *index != failed)
+            .chain([failed]);
+
+        match self
+            .connector
+            .connect_first_available(&self.endpoints, candidates, &self.metrics)
+            .await
+        {
+            Ok(active) => {
+                warn!(
+                    "failing over from rpc endpoint {} to {}",
+                    self.endpoints[failed].name, self.endpoints[active.index].name
+                );
+                self.metrics.eth_rpc_failovers.inc();
+                self.active.send_replace(active);
+                *last_switch = Instant::now();
+            }
+            Err(err) => error!("could not fail over to any rpc endpoint: {err}"),
+        }
+    }
+
+    /// Switches back to the highest priority endpoint answering again, should the endpoints
+    /// preferred over the `current` one be due for a probe.
+    async fn fail_back_if_due(&self, current: usize) {
+        // whoever holds the lock is switching endpoints already
+        let Ok(mut last_switch) = self.last_switch.try_lock() else {
+            return;
+        };
+        if self.active.borrow().index != current || last_switch.elapsed() < self.reprobe_interval {
+            return;
+        }
+        *last_switch = Instant::now();
+
+        for index in 0..current {
+            let endpoint = &self.endpoints[index];
+            let probe = match self.connector.connect(&endpoint.url).await {
+                Ok(connection) => connection
+                    .get_block_number()
+                    .await
+                    .map(|_| ActiveConnection { index, connection }),
+                Err(err) => Err(err),
+            };
+
+            match probe {
+                Ok(active) => {
+                    endpoint.health_tracker.note_success();
+                    self.metrics.note_health(endpoint);
+                    info!(
+                        "rpc endpoint {} answers again, switching back from {}",
+                        endpoint.name, self.endpoints[current].name
+                    );
+                    self.metrics.eth_rpc_failovers.inc();
+                    self.active.send_replace(active);
+                    return;
+                }
+                Err(err) => warn!("rpc endpoint {} still unavailable: {err}", endpoint.name),
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct Connector {
+    contract_address: Address,
+    signer: L1Signer,
+    commit_interval: NonZeroU32,
+    logs_block_range: NonZeroU32,
+}
+
+impl Connector {
+    pub(super) fn new(
+        contract_address: Address,
+        signer: L1Signer,
+        commit_interval: NonZeroU32,
+        logs_block_range: NonZeroU32,
+    ) -> Self {
+        Self {
+            contract_address,
+            signer,
+            commit_interval,
+            logs_block_range,
+        }
+    }
+
+    pub(super) async fn connect(&self, url: &Url) -> Result<RpcConnection> {
+        RpcConnection::connect(
+            url,
+            self.contract_address,
+            self.signer.clone(),
+            self.commit_interval,
+            self.logs_block_range,
+        )
+        .await
+    }
+
+    async fn connect_first_available(
+        &self,
+        endpoints: &[Endpoint],
+        candidates: impl Iterator<Item = usize>,
+        metrics: &Metrics,
+    ) -> Result<ActiveConnection> {
+        let mut last_error = Error::Other("no rpc endpoints configured".to_string());
+
+        for index in candidates {
+            let endpoint = &endpoints[index];
+            match self.connect(&endpoint.url).await {
+                Ok(connection) => return Ok(ActiveConnection { index, connection }),
+                Err(err) => {
+                    warn!("could not connect to rpc endpoint {}: {err}", endpoint.name);
+                    endpoint.health_tracker.note_failure();
+                    metrics.note_health(endpoint);
+                    last_error = err;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl EthApi for FailoverConnection {
+    async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx> {
+        let active = self.active();
+        let response = active.connection.submit(block, nonce).await;
+        self.track(active.index, response).await
+    }
+
+    async fn submit_with_blobs(&self, block: FuelBlock, data: Vec<u8>, nonce: u64) -> Result<L1Tx> {
+        let active = self.active();
+        let response = active
+            .connection
+            .submit_with_blobs(block, data, nonce)
+            .await;
+        self.track(active.index, response).await
+    }
+
+    async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx> {
+        let active = self.active();
+        let response = active
+            .connection
+            .resubmit(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)
+            .await;
+        self.track(active.index, response).await
+    }
+
+    async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx> {
+        let active = self.active();
+        let response = active
+            .connection
+            .resubmit_with_blobs(
+                block,
+                data,
+                nonce,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            )
+            .await;
+        self.track(active.index, response).await
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        let active = self.active();
+        let response = active.connection.get_block_number().await;
+        self.track(active.index, response).await
+    }
+
+    async fn balance(&self) -> Result<U256> {
+        let active = self.active();
+        let response = active.connection.balance().await;
+        self.track(active.index, response).await
+    }
+
+    async fn pending_nonce(&self) -> Result<u64> {
+        let active = self.active();
+        let response = active.connection.pending_nonce().await;
+        self.track(active.index, response).await
+    }
+
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState> {
+        let active = self.active();
+        let response = active.connection.tx_state(tx_hash).await;
+        self.track(active.index, response).await
+    }
+
+    async fn block_hash_at(&self, height: u64) -> Result<Option<[u8; 32]>> {
+        let active = self.active();
+        let response = active.connection.block_hash_at(height).await;
+        self.track(active.index, response).await
+    }
+
+    fn event_streamer(&self, eth_block_height: u64) -> EthEventStreamer {
+        self.active()
+            .connection
+            .event_streamer(eth_block_height)
+            .until_failover(self.active.subscribe())
+    }
+
+    async fn finalized(&self, block: FuelBlock) -> Result<bool> {
+        let active = self.active();
+        let response = active.connection.finalized(block).await;
+        self.track(active.index, response).await
+    }
+
+    async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]> {
+        let active = self.active();
+        let response = active
+            .connection
+            .block_hash_at_commit_height(commit_height)
+            .await;
+        self.track(active.index, response).await
+    }
+
+    async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_block: u64,
+    ) -> Result<Option<FuelBlockCommittedOnL1>> {
+        let active = self.active();
+        let response = active.connection.commit_event(block, from_block).await;
+        self.track(active.index, response).await
+    }
+
+    async fn paused(&self) -> Result<bool> {
+        let active = self.active();
+        let response = active.connection.paused().await;
+        self.track(active.index, response).await
+    }
+
+    async fn pause_changes(&self, from_block: u64, to_block: u64) -> Result<Option<bool>> {
+        let active = self.active();
+        let response = active.connection.pause_changes(from_block, to_block).await;
+        self.track(active.index, response).await
+    }
+}
+
+impl RegistersMetrics for FailoverConnection {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![
+            Box::new(self.metrics.eth_rpc_endpoint_healthy.clone()),
+            Box::new(self.metrics.eth_rpc_failovers.clone()),
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    eth_rpc_endpoint_healthy: IntGaugeVec,
+    eth_rpc_failovers: IntCounter,
+}
+
+impl Metrics {
+    fn note_health(&self, endpoint: &Endpoint) {
+        self.eth_rpc_endpoint_healthy
+            .with_label_values(&[&endpoint.name])
+            .set(i64::from(endpoint.health_tracker.healthy()));
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let eth_rpc_endpoint_healthy = IntGaugeVec::new(
+            Opts::new(
+                "eth_rpc_endpoint_healthy",
+                "Whether the Ethereum RPC endpoint is healthy (1) or not (0).",
+            ),
+            &["endpoint"],
+        )
+        .expect("eth_rpc_endpoint_healthy metric to be correctly configured");
+
+        let eth_rpc_failovers = IntCounter::with_opts(Opts::new(
+            "eth_rpc_failovers",
+            "Number of times requests were moved over to another Ethereum RPC endpoint.",
+        ))
+        .expect("eth_rpc_failovers metric to be correctly configured");
+
+        Self {
+            eth_rpc_endpoint_healthy,
+            eth_rpc_failovers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::test_utils::{given_signer, serve_rpc, spawn_rpc_server};
+
+    #[tokio::test]
+    async fn fails_over_to_the_next_endpoint_after_network_errors() {
+        // given
+        let unreachable = given_unreachable_url().await;
+        let reachable = spawn_rpc_server(|_, _| json!("0x2a")).await;
+        let failover = given_failover(&[unreachable, reachable], 1, Duration::MAX).await;
+
+        // when
+        let first = failover.get_block_number().await;
+        let second = failover.get_block_number().await;
+
+        // then
+        assert!(matches!(first, Err(Error::Network(_))));
+        assert_eq!(second.unwrap(), 42);
+
+        let health = failover
+            .endpoint_health_checkers()
+            .iter()
+            .map(|(_, checker)| checker.healthy())
+            .collect::<Vec<_>>();
+        assert_eq!(health, vec![false, true]);
+    }
+
+    #[tokio::test]
+    async fn stays_on_the_endpoint_while_it_is_healthy() {
+        // given
+        let unreachable = given_unreachable_url().await;
+        let reachable = spawn_rpc_server(|_, _| json!("0x2a")).await;
+        let failover = given_failover(&[unreachable, reachable], 2, Duration::MAX).await;
+
+        // when
+        let response = failover.get_block_number().await;
+
+        // then
+        assert!(matches!(response, Err(Error::Network(_))));
+        assert_eq!(failover.active().index, 0);
+    }
+
+    #[tokio::test]
+    async fn switches_back_once_a_preferred_endpoint_answers_again() {
+        // given
+        let primary = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let primary_addr = primary.local_addr().unwrap();
+        drop(primary);
+        let primary_url = format!("http://{primary_addr}").parse().unwrap();
+        let secondary = spawn_rpc_server(|_, _| json!("0x2a")).await;
+        let failover = given_failover(&[primary_url, secondary], 1, Duration::ZERO).await;
+
+        let _ = failover.get_block_number().await;
+        let while_down = failover.get_block_number().await;
+        assert_eq!(failover.active().index, 1);
+
+        // when
+        let primary = TcpListener::bind(primary_addr).await.unwrap();
+        serve_rpc(primary, |_, _| json!("0x1"));
+        let before_switching_back = failover.get_block_number().await;
+        let after_switching_back = failover.get_block_number().await;
+
+        // then
+        assert_eq!(while_down.unwrap(), 42);
+        assert_eq!(before_switching_back.unwrap(), 42);
+        assert_eq!(after_switching_back.unwrap(), 1);
+        assert_eq!(failover.active().index, 0);
+        assert!(failover.endpoint_health_checkers()[0].1.healthy());
+    }
+
+    #[tokio::test]
+    async fn probes_preferred_endpoints_only_once_the_interval_passed() {
+        // given
+        let primary = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let primary_addr = primary.local_addr().unwrap();
+        drop(primary);
+        let primary_url = format!("http://{primary_addr}").parse().unwrap();
+        let secondary = spawn_rpc_server(|_, _| json!("0x2a")).await;
+        let failover =
+            given_failover(&[primary_url, secondary], 1, Duration::from_secs(3600)).await;
+        let _ = failover.get_block_number().await;
+
+        // when
+        let primary = TcpListener::bind(primary_addr).await.unwrap();
+        serve_rpc(primary, |_, _| json!("0x1"));
+        let response = failover.get_block_number().await;
+
+        // then
+        assert_eq!(response.unwrap(), 42);
+        assert_eq!(failover.active().index, 1);
+    }
+
+    #[tokio::test]
+    async fn event_streams_are_told_about_failovers() {
+        // given
+        let unreachable = given_unreachable_url().await;
+        let reachable = spawn_rpc_server(|_, _| json!("0x2a")).await;
+        let failover = given_failover(&[unreachable, reachable], 1, Duration::MAX).await;
+        let failed_over = failover.active.subscribe();
+
+        // when
+        let _ = failover.get_block_number().await;
+
+        // then
+        assert!(failed_over.has_changed().unwrap());
+    }
+
+    async fn given_failover(
+        urls: &[Url],
+        unhealthy_after_n_errors: usize,
+        reprobe_interval: Duration,
+    ) -> FailoverConnection {
+        let signer = given_signer();
+
+        FailoverConnection::connect(
+            urls,
+            Address::zero(),
+            signer,
+            1.try_into().unwrap(),
+            1000.try_into().unwrap(),
+            unhealthy_after_n_errors,
+            reprobe_interval,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn given_unreachable_url() -> Url {
+        // nothing listens on the port once the listener is dropped
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        url.parse().unwrap()
+    }
+}
@@ -1,25 +1,49 @@
 use ::metrics::{
     prometheus::core::Collector, ConnectionHealthTracker, HealthChecker, RegistersMetrics,
 };
-use ports::types::{FuelBlock, U256};
+use ports::types::{FuelBlock, FuelBlockCommittedOnL1, L1Tx, TxState, U256};
 
-use crate::{
-    error::{Error, Result},
-    metrics::Metrics,
-    websocket::event_streamer::EthEventStreamer,
-};
+use crate::{error::Result, metrics::Metrics, rpc::event_streamer::EthEventStreamer};
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait EthApi {
-    async fn submit(&self, block: FuelBlock) -> Result<()>;
+    async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx>;
+    async fn submit_with_blobs(&self, block: FuelBlock, data: Vec<u8>, nonce: u64) -> Result<L1Tx>;
+    async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx>;
+    async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx>;
     async fn get_block_number(&self) -> Result<u64>;
     async fn balance(&self) -> Result<U256>;
+    async fn pending_nonce(&self) -> Result<u64>;
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState>;
+    async fn block_hash_at(&self, height: u64) -> Result<Option<[u8; 32]>>;
     fn event_streamer(&self, eth_block_height: u64) -> EthEventStreamer;
-    #[cfg(feature = "test-helpers")]
     async fn finalized(&self, block: FuelBlock) -> Result<bool>;
-    #[cfg(feature = "test-helpers")]
     async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]>;
+    /// The commit event of `block` emitted at or after `from_block`, `None` if there is none.
+    async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_block: u64,
+    ) -> Result<Option<FuelBlockCommittedOnL1>>;
+    async fn paused(&self) -> Result<bool>;
+    /// Whether the last `Paused`/`Unpaused` event within the given blocks paused the contract,
+    /// `None` if there was no such event.
+    async fn pause_changes(&self, from_block: u64, to_block: u64) -> Result<Option<bool>>;
 }
 
 #[derive(Clone)]
@@ -47,7 +71,7 @@ impl<T> HealthTrackingMiddleware<T> {
             Ok(_val) => {
                 self.health_tracker.note_success();
             }
-            Err(Error::Network(..)) => {
+            Err(err) if err.is_network() => {
                 self.metrics.eth_network_errors.inc();
                 self.health_tracker.note_failure();
             }
@@ -68,8 +92,53 @@ impl<T> EthApi for HealthTrackingMiddleware<T>
 where
     T: EthApi + Send + Sync,
 {
-    async fn submit(&self, block: FuelBlock) -> Result<()> {
-        let response = self.adapter.submit(block).await;
+    async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx> {
+        let response = self.adapter.submit(block, nonce).await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn submit_with_blobs(&self, block: FuelBlock, data: Vec<u8>, nonce: u64) -> Result<L1Tx> {
+        let response = self.adapter.submit_with_blobs(block, data, nonce).await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx> {
+        let response = self
+            .adapter
+            .resubmit(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)
+            .await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx> {
+        let response = self
+            .adapter
+            .resubmit_with_blobs(
+                block,
+                data,
+                nonce,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            )
+            .await;
         self.note_network_status(&response);
         response
     }
@@ -90,16 +159,59 @@ where
         response
     }
 
-    #[cfg(feature = "test-helpers")]
+    async fn pending_nonce(&self) -> Result<u64> {
+        let response = self.adapter.pending_nonce().await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState> {
+        let response = self.adapter.tx_state(tx_hash).await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn block_hash_at(&self, height: u64) -> Result<Option<[u8; 32]>> {
+        let response = self.adapter.block_hash_at(height).await;
+        self.note_network_status(&response);
+        response
+    }
+
     async fn finalized(&self, block: FuelBlock) -> Result<bool> {
-        self.adapter.finalized(block).await
+        let response = self.adapter.finalized(block).await;
+        self.note_network_status(&response);
+        response
     }
 
-    #[cfg(feature = "test-helpers")]
     async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]> {
-        self.adapter
+        let response = self
+            .adapter
             .block_hash_at_commit_height(commit_height)
-            .await
+            .await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_block: u64,
+    ) -> Result<Option<FuelBlockCommittedOnL1>> {
+        let response = self.adapter.commit_event(block, from_block).await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn paused(&self) -> Result<bool> {
+        let response = self.adapter.paused().await;
+        self.note_network_status(&response);
+        response
+    }
+
+    async fn pause_changes(&self, from_block: u64, to_block: u64) -> Result<Option<bool>> {
+        let response = self.adapter.pause_changes(from_block, to_block).await;
+        self.note_network_status(&response);
+        response
     }
 }
 
@@ -108,6 +220,7 @@ mod tests {
     use ::metrics::prometheus::{proto::Metric, Registry};
 
     use super::*;
+    use crate::error::Error;
 
     #[tokio::test]
     async fn recovers_after_successful_network_request() {
@@ -115,7 +228,7 @@ mod tests {
         let mut eth_adapter = MockEthApi::new();
         eth_adapter
             .expect_submit()
-            .returning(|_| Err(Error::Network("An error".into())));
+            .returning(|_, _| Err(Error::Network("An error".into())));
 
         eth_adapter
             .expect_get_block_number()
@@ -124,7 +237,7 @@ mod tests {
         let adapter = HealthTrackingMiddleware::new(eth_adapter, 1);
         let health_check = adapter.connection_health_checker();
 
-        let _ = adapter.submit(given_a_block(42)).await;
+        let _ = adapter.submit(given_a_block(42), 0).await;
 
         // when
         let _ = adapter.get_block_number().await;
@@ -139,7 +252,7 @@ mod tests {
         let mut eth_adapter = MockEthApi::new();
         eth_adapter
             .expect_submit()
-            .returning(|_| Err(Error::Other("An error".into())));
+            .returning(|_, _| Err(Error::Other("An error".into())));
 
         eth_adapter
             .expect_get_block_number()
@@ -148,7 +261,7 @@ mod tests {
         let adapter = HealthTrackingMiddleware::new(eth_adapter, 2);
         let health_check = adapter.connection_health_checker();
 
-        let _ = adapter.submit(given_a_block(42)).await;
+        let _ = adapter.submit(given_a_block(42), 0).await;
 
         // when
         let _ = adapter.get_block_number().await;
@@ -162,7 +275,7 @@ mod tests {
         let mut eth_adapter = MockEthApi::new();
         eth_adapter
             .expect_submit()
-            .returning(|_| Err(Error::Network("An error".into())));
+            .returning(|_, _| Err(Error::Network("An error".into())));
 
         eth_adapter
             .expect_get_block_number()
@@ -172,7 +285,7 @@ mod tests {
         let health_check = adapter.connection_health_checker();
         assert!(health_check.healthy());
 
-        let _ = adapter.submit(given_a_block(42)).await;
+        let _ = adapter.submit(given_a_block(42), 0).await;
         assert!(health_check.healthy());
 
         let _ = adapter.get_block_number().await;
@@ -187,7 +300,7 @@ mod tests {
         let mut eth_adapter = MockEthApi::new();
         eth_adapter
             .expect_submit()
-            .returning(|_| Err(Error::Network("An error".into())));
+            .returning(|_, _| Err(Error::Network("An error".into())));
 
         eth_adapter
             .expect_get_block_number()
@@ -197,7 +310,7 @@ mod tests {
         let adapter = HealthTrackingMiddleware::new(eth_adapter, 3);
         adapter.register_metrics(&registry);
 
-        let _ = adapter.submit(given_a_block(42)).await;
+        let _ = adapter.submit(given_a_block(42), 0).await;
         let _ = adapter.get_block_number().await;
 
         let metrics = registry.gather();
@@ -0,0 +1,377 @@
+use std::{future::Future, num::NonZeroU32, sync::Arc};
+
+use ::metrics::{
+    prometheus::{core::Collector, IntCounterVec, Opts},
+    ConnectionHealthTracker, HealthChecker, RegistersMetrics,
+};
+use futures::future::join_all;
+use ports::types::FuelBlockCommittedOnL1;
+use tokio::sync::Mutex;
+use tracing::{error, warn};
+use url::Url;
+
+use super::{connection::RpcConnection, failover::Connector, health_tracking_middleware::EthApi};
+use crate::error::{Error, Result};
+
+// How many reads in a row may lack a quorum before it is reported as unhealthy.
+const UNHEALTHY_AFTER_N_DISAGREEMENTS: usize = 3;
+
+struct QuorumEndpoint {
+    name: String,
+    url: Url,
+    // connected on first use and after every network error
+    connection: Mutex<Option<RpcConnection>>,
+}
+
+/// Asks every rpc endpoint and only accepts answers that at least `required` of them agree on,
+/// so that a single lagging or lying endpoint cannot mislead the committer.
+#[derive(Clone)]
+pub(crate) struct Quorum {
+    required: usize,
+    endpoints: Arc<[QuorumEndpoint]>,
+    connector: Connector,
+    health_tracker: ConnectionHealthTracker,
+    metrics: Metrics,
+}
+
+impl Quorum {
+    pub(super) fn new(
+        endpoints: Vec<(String, Url)>,
+        connector: Connector,
+        required: NonZeroU32,
+    ) -> Result<Self> {
+        let required = required.get() as usize;
+        if required > endpoints.len() {
+            return Err(Error::Other(format!(
+                "a quorum of {required} needs as many rpc endpoints, only {} configured",
+                endpoints.len()
+            )));
+        }
+
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(name, url)| QuorumEndpoint {
+                name,
+                url,
+                connection: Mutex::new(None),
+            })
+            .collect();
+
+        Ok(Self {
+            required,
+            endpoints,
+            connector,
+            health_tracker: ConnectionHealthTracker::new(UNHEALTHY_AFTER_N_DISAGREEMENTS),
+            metrics: Metrics::default(),
+        })
+    }
+
+    pub(crate) fn health_checker(&self) -> HealthChecker {
+        self.health_tracker.tracker()
+    }
+
+    /// The highest block number reached by at least `required` endpoints.
+    pub(crate) async fn block_number(&self) -> Result<u64> {
+        let mut heights = self
+            .ask_all(|connection| async move { connection.get_block_number().await })
+            .await
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect::<Vec<_>>();
+        heights.sort_unstable_by(|a, b| b.cmp(a));
+
+        if let Some(height) = heights.get(self.required - 1) {
+            self.health_tracker.note_success();
+            return Ok(*height);
+        }
+
+        let msg = format!(
+            "only {} of the {} required rpc endpoints reported a block number",
+            heights.len(),
+            self.required
+        );
+        self.note_disagreement("get_block_number", &msg);
+        Err(Error::NoQuorum(msg))
+    }
+
+    /// Whether at least `required` endpoints report `event` in the same L1 block.
+    pub(crate) async fn confirms(&self, event: &FuelBlockCommittedOnL1) -> bool {
+        let block_hash = event.included_in.hash;
+        let confirmations = self
+            .ask_all(|connection| async move { connection.commits_in_block(block_hash).await })
+            .await
+            .into_iter()
+            .filter(|commits| {
+                commits.as_ref().is_ok_and(|commits| {
+                    commits.iter().any(|commit| {
+                        commit.fuel_block_hash == event.fuel_block_hash
+                            && commit.commit_height == event.commit_height
+                            && commit.included_in == event.included_in
+                    })
+                })
+            })
+            .count();
+
+        if confirmations >= self.required {
+            self.health_tracker.note_success();
+            return true;
+        }
+
+        let msg = format!(
+            "{event:?} was reported by {confirmations} of the {} required rpc endpoints",
+            self.required
+        );
+        self.note_disagreement("commit_event", &msg);
+        false
+    }
+
+    fn note_disagreement(&self, request: &str, msg: &str) {
+        error!("no quorum among the rpc endpoints: {msg}");
+        self.health_tracker.note_failure();
+        self.metrics
+            .eth_rpc_quorum_disagreements
+            .with_label_values(&[request])
+            .inc();
+    }
+
+    async fn ask_all<F, Fut, T>(&self, request: F) -> Vec<Result<T>>
+    where
+        F: Fn(RpcConnection) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        join_all(
+            self.endpoints
+                .iter()
+                .map(|endpoint| self.ask(endpoint, &request)),
+        )
+        .await
+    }
+
+    async fn ask<F, Fut, T>(&self, endpoint: &QuorumEndpoint, request: &F) -> Result<T>
+    where
+        F: Fn(RpcConnection) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let response = match self.connection(endpoint).await {
+            Ok(connection) => request(connection).await,
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = &response {
+            warn!(
+                "rpc endpoint {} left out of the quorum: {err}",
+                endpoint.name
+            );
+            if err.is_network() {
+                *endpoint.connection.lock().await = None;
+            }
+        }
+
+        response
+    }
+
+    async fn connection(&self, endpoint: &QuorumEndpoint) -> Result<RpcConnection> {
+        let mut connection = endpoint.connection.lock().await;
+        if let Some(connection) = connection.as_ref() {
+            return Ok(connection.clone());
+        }
+
+        let connected = self.connector.connect(&endpoint.url).await?;
+        *connection = Some(connected.clone());
+
+        Ok(connected)
+    }
+}
+
+impl RegistersMetrics for Quorum {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.metrics.eth_rpc_quorum_disagreements.clone())]
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    eth_rpc_quorum_disagreements: IntCounterVec,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let eth_rpc_quorum_disagreements = IntCounterVec::new(
+            Opts::new(
+                "eth_rpc_quorum_disagreements",
+                "Number of reads for which not enough Ethereum RPC endpoints agreed on the answer.",
+            ),
+            &["request"],
+        )
+        .expect("eth_rpc_quorum_disagreements metric to be correctly configured");
+
+        Self {
+            eth_rpc_quorum_disagreements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::{
+        contract::EthEvent,
+        types::{Address, H160, H256},
+    };
+    use ports::types::{L1Block, U256};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        rpc::connection::CommitSubmittedFilter,
+        test_utils::{given_signer, spawn_rpc_server},
+    };
+
+    #[tokio::test]
+    async fn block_number_is_the_highest_reached_by_the_quorum() {
+        // given
+        let mut urls = vec![];
+        for height in ["0x5", "0x7", "0x64"] {
+            urls.push(spawn_rpc_server(move |_, _| json!(height)).await);
+        }
+        let quorum = given_quorum(urls, 2);
+
+        // when
+        let height = quorum.block_number().await.unwrap();
+
+        // then
+        assert_eq!(height, 7);
+    }
+
+    #[tokio::test]
+    async fn missing_quorum_is_reported() {
+        // given
+        let urls = vec![
+            spawn_rpc_server(|_, _| json!("0x5")).await,
+            spawn_rpc_server(|_, _| json!({"not": "a block number"})).await,
+        ];
+        let quorum = given_quorum(urls, 2);
+        let health = quorum.health_checker();
+
+        // when
+        let mut results = vec![];
+        for _ in 0..UNHEALTHY_AFTER_N_DISAGREEMENTS {
+            results.push(quorum.block_number().await);
+        }
+
+        // then
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(Error::NoQuorum(_)))));
+        assert!(!health.healthy());
+    }
+
+    #[tokio::test]
+    async fn quorum_cannot_exceed_the_endpoints() {
+        // given
+        let urls = vec![spawn_rpc_server(|_, _| json!("0x5")).await];
+
+        // when
+        let result = Quorum::new(given_named(urls), given_connector(), 2.try_into().unwrap());
+
+        // then
+        assert!(matches!(result, Err(Error::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn commit_event_is_confirmed_by_the_quorum() {
+        // given
+        let event = given_commit_event();
+        let urls = vec![
+            spawn_rpc_server(move |_, _| json!([given_log(&event, event.fuel_block_hash)])).await,
+            spawn_rpc_server(move |_, _| json!([given_log(&event, event.fuel_block_hash)])).await,
+            spawn_rpc_server(move |_, _| json!([given_log(&event, [2; 32])])).await,
+        ];
+        let quorum = given_quorum(urls, 2);
+
+        // when
+        let confirmed = quorum.confirms(&event).await;
+
+        // then
+        assert!(confirmed);
+    }
+
+    #[tokio::test]
+    async fn commit_event_is_not_confirmed_without_a_quorum() {
+        // given
+        let event = given_commit_event();
+        let urls = vec![
+            spawn_rpc_server(move |_, _| json!([given_log(&event, event.fuel_block_hash)])).await,
+            spawn_rpc_server(move |_, _| json!([given_log(&event, [2; 32])])).await,
+            spawn_rpc_server(|_, _| json!([])).await,
+        ];
+        let quorum = given_quorum(urls, 2);
+        let health = quorum.health_checker();
+
+        // when
+        let confirmed = quorum.confirms(&event).await;
+
+        // then
+        assert!(!confirmed);
+        assert_eq!(
+            quorum
+                .metrics
+                .eth_rpc_quorum_disagreements
+                .with_label_values(&["commit_event"])
+                .get(),
+            1
+        );
+        assert!(health.healthy());
+    }
+
+    fn given_commit_event() -> FuelBlockCommittedOnL1 {
+        FuelBlockCommittedOnL1 {
+            fuel_block_hash: [1; 32],
+            commit_height: U256::from(7),
+            included_in: L1Block {
+                height: 3u32.into(),
+                hash: [3; 32],
+            },
+        }
+    }
+
+    fn given_log(event: &FuelBlockCommittedOnL1, fuel_block_hash: [u8; 32]) -> Value {
+        json!({
+            "address": H160::zero(),
+            "topics": [
+                CommitSubmittedFilter::signature(),
+                H256::from_low_u64_be(event.commit_height.as_u64()),
+            ],
+            "data": H256(fuel_block_hash),
+            "blockNumber": "0x3",
+            "blockHash": H256(event.included_in.hash),
+            "transactionHash": H256::random(),
+            "transactionIndex": "0x0",
+            "logIndex": "0x0",
+            "removed": false,
+        })
+    }
+
+    fn given_quorum(urls: Vec<Url>, required: u32) -> Quorum {
+        Quorum::new(
+            given_named(urls),
+            given_connector(),
+            required.try_into().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn given_named(urls: Vec<Url>) -> Vec<(String, Url)> {
+        urls.into_iter().map(|url| (url.to_string(), url)).collect()
+    }
+
+    fn given_connector() -> Connector {
+        let signer = given_signer();
+
+        Connector::new(
+            Address::zero(),
+            signer,
+            1.try_into().unwrap(),
+            1000.try_into().unwrap(),
+        )
+    }
+}
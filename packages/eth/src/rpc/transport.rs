@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use ethers::{
+    providers::{
+        Http, HttpClientError, JsonRpcClient, JsonRpcError, ProviderError, PubsubClient, RpcError,
+        Ws, WsClientError,
+    },
+    types::U256,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+use crate::error::{Error, Result};
+
+/// JSON-RPC transport picked by the scheme of the RPC url. Only websockets support subscriptions,
+/// over HTTP events have to be polled for.
+#[derive(Debug, Clone)]
+pub enum Transport {
+    Ws(Ws),
+    Http(Http),
+}
+
+impl Transport {
+    pub async fn connect(url: &Url) -> Result<Self> {
+        match url.scheme() {
+            "ws" | "wss" => {
+                let ws = Ws::connect(url.as_str())
+                    .await
+                    .map_err(|e| Error::Network(e.to_string()))?;
+                Ok(Self::Ws(ws))
+            }
+            "http" | "https" => Ok(Self::Http(Http::new(url.clone()))),
+            scheme => Err(Error::Other(format!(
+                "unsupported scheme `{scheme}` in rpc url, expected one of ws, wss, http or https"
+            ))),
+        }
+    }
+
+    pub fn supports_subscriptions(&self) -> bool {
+        matches!(self, Self::Ws(_))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    #[error(transparent)]
+    Ws(#[from] WsClientError),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("subscriptions are not supported over http")]
+    SubscriptionsUnsupported,
+}
+
+impl RpcError for TransportError {
+    fn as_error_response(&self) -> Option<&JsonRpcError> {
+        match self {
+            Self::Ws(err) => err.as_error_response(),
+            Self::Http(err) => err.as_error_response(),
+            Self::SubscriptionsUnsupported => None,
+        }
+    }
+
+    fn as_serde_error(&self) -> Option<&serde_json::Error> {
+        match self {
+            Self::Ws(err) => err.as_serde_error(),
+            Self::Http(err) => err.as_serde_error(),
+            Self::SubscriptionsUnsupported => None,
+        }
+    }
+}
+
+impl From<TransportError> for ProviderError {
+    fn from(err: TransportError) -> Self {
+        Self::JsonRpcClientError(Box::new(err))
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for Transport {
+    type Error = TransportError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: std::fmt::Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        match self {
+            Self::Ws(ws) => Ok(ws.request(method, params).await?),
+            Self::Http(http) => Ok(http.request(method, params).await?),
+        }
+    }
+}
+
+impl PubsubClient for Transport {
+    type NotificationStream = <Ws as PubsubClient>::NotificationStream;
+
+    fn subscribe<T: Into<U256>>(
+        &self,
+        id: T,
+    ) -> std::result::Result<Self::NotificationStream, Self::Error> {
+        match self {
+            Self::Ws(ws) => Ok(ws.subscribe(id)?),
+            Self::Http(_) => Err(TransportError::SubscriptionsUnsupported),
+        }
+    }
+
+    fn unsubscribe<T: Into<U256>>(&self, id: T) -> std::result::Result<(), Self::Error> {
+        match self {
+            Self::Ws(ws) => Ok(ws.unsubscribe(id)?),
+            Self::Http(_) => Err(TransportError::SubscriptionsUnsupported),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn http_urls_select_the_polling_transport() {
+        // given
+        let url = Url::parse("http://localhost:8545").unwrap();
+
+        // when
+        let transport = Transport::connect(&url).await.unwrap();
+
+        // then
+        assert!(matches!(transport, Transport::Http(_)));
+        assert!(!transport.supports_subscriptions());
+        assert!(matches!(
+            transport.subscribe(1),
+            Err(TransportError::SubscriptionsUnsupported)
+        ));
+    }
+
+    #[tokio::test]
+    async fn unsupported_scheme_causes_an_error() {
+        // given
+        let url = Url::parse("ipc:///tmp/geth.ipc").unwrap();
+
+        // when
+        let result = Transport::connect(&url).await;
+
+        // then
+        let Err(Error::Other(msg)) = result else {
+            panic!("expected an error");
+        };
+        assert!(msg.contains("unsupported scheme `ipc`"));
+    }
+}
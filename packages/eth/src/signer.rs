@@ -0,0 +1,301 @@
+use std::{path::Path, str::FromStr};
+
+use async_trait::async_trait;
+use ethers::{
+    providers::{Http, JsonRpcClient},
+    signers::{to_eip155_v, LocalWallet, Signer, WalletError},
+    types::{
+        transaction::{eip2718::TypedTransaction, eip712::Eip712},
+        Address, Bytes, Chain, Signature,
+    },
+    utils::rlp::Rlp,
+};
+use serde::Deserialize;
+use url::Url;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("wallet error: {0}")]
+    Wallet(#[from] WalletError),
+    #[error("remote signer error: {0}")]
+    Remote(String),
+}
+
+/// Signs the commit transactions, either with a key held in memory or by delegating to an
+/// external signing service.
+#[derive(Debug, Clone)]
+pub enum L1Signer {
+    Local(LocalWallet),
+    Remote(RemoteSigner),
+}
+
+impl L1Signer {
+    pub fn local(wallet_key: &str, chain_id: Chain) -> ports::l1::Result<Self> {
+        let wallet = LocalWallet::from_str(wallet_key)
+            .map_err(crate::error::Error::from)?
+            .with_chain_id(chain_id);
+        Ok(Self::Local(wallet))
+    }
+
+    /// Decrypts the key out of a JSON keystore as produced by geth or `cast wallet`.
+    pub fn keystore(path: &Path, password: &str, chain_id: Chain) -> ports::l1::Result<Self> {
+        let wallet = LocalWallet::decrypt_keystore(path, password)
+            .map_err(|e| {
+                crate::error::Error::Other(format!(
+                    "could not decrypt keystore `{}`: {e}",
+                    path.display()
+                ))
+            })?
+            .with_chain_id(chain_id);
+        Ok(Self::Local(wallet))
+    }
+
+    #[must_use]
+    pub fn remote(url: Url, address: Address, chain_id: Chain) -> Self {
+        Self::Remote(RemoteSigner::new(url, address, chain_id))
+    }
+
+    /// Signs a tx hash as is, for tx types ethers cannot sign by itself.
+    #[cfg(feature = "blobs")]
+    pub(crate) fn sign_hash(&self, hash: ethers::types::H256) -> Result<Signature, SignerError> {
+        match self {
+            Self::Local(wallet) => Ok(wallet.sign_hash(hash)?),
+            Self::Remote(_) => Err(SignerError::Remote(
+                "signing blob txs is not supported".to_string(),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for L1Signer {
+    type Error = SignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        match self {
+            Self::Local(wallet) => Ok(wallet.sign_message(message).await?),
+            Self::Remote(signer) => signer.sign_message(message).await,
+        }
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        match self {
+            Self::Local(wallet) => Ok(wallet.sign_transaction(tx).await?),
+            Self::Remote(signer) => signer.sign_transaction(tx).await,
+        }
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        match self {
+            Self::Local(wallet) => Ok(wallet.sign_typed_data(payload).await?),
+            Self::Remote(_) => Err(SignerError::Remote(
+                "signing typed data is not supported".to_string(),
+            )),
+        }
+    }
+
+    fn address(&self) -> Address {
+        match self {
+            Self::Local(wallet) => wallet.address(),
+            Self::Remote(signer) => signer.address,
+        }
+    }
+
+    fn chain_id(&self) -> u64 {
+        match self {
+            Self::Local(wallet) => wallet.chain_id(),
+            Self::Remote(signer) => signer.chain_id,
+        }
+    }
+
+    fn with_chain_id<T: Into<u64>>(self, chain_id: T) -> Self {
+        match self {
+            Self::Local(wallet) => Self::Local(wallet.with_chain_id(chain_id)),
+            Self::Remote(signer) => Self::Remote(RemoteSigner {
+                chain_id: chain_id.into(),
+                ..signer
+            }),
+        }
+    }
+}
+
+/// Signs through the `eth_sign` and `eth_signTransaction` JSON-RPC methods as served by a node
+/// managing the account or by Web3Signer.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: Http,
+    address: Address,
+    chain_id: u64,
+}
+
+// Web3Signer answers `eth_signTransaction` with the raw tx while geth wraps it in an object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SignedTransaction {
+    Raw(Bytes),
+    Wrapped { raw: Bytes },
+}
+
+impl RemoteSigner {
+    fn new(url: Url, address: Address, chain_id: Chain) -> Self {
+        Self {
+            client: Http::new(url),
+            address,
+            chain_id: chain_id.into(),
+        }
+    }
+
+    async fn sign_message(&self, message: impl AsRef<[u8]>) -> Result<Signature, SignerError> {
+        let message = Bytes::from(message.as_ref().to_vec());
+        let signature: Bytes = self
+            .client
+            .request("eth_sign", (self.address, message))
+            .await
+            .map_err(|e| SignerError::Remote(e.to_string()))?;
+
+        Signature::try_from(signature.as_ref()).map_err(|e| SignerError::Remote(e.to_string()))
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, SignerError> {
+        let mut tx = tx.clone();
+        tx.set_from(self.address);
+        let chain_id = tx.chain_id().map_or(self.chain_id, |id| id.as_u64());
+        tx.set_chain_id(chain_id);
+
+        let signed: SignedTransaction = self
+            .client
+            .request("eth_signTransaction", [&tx])
+            .await
+            .map_err(|e| SignerError::Remote(e.to_string()))?;
+        let raw = match signed {
+            SignedTransaction::Raw(raw) | SignedTransaction::Wrapped { raw } => raw,
+        };
+
+        let (_, mut signature) = TypedTransaction::decode_signed(&Rlp::new(&raw))
+            .map_err(|e| SignerError::Remote(format!("could not decode signed tx: {e}")))?;
+
+        // guards against the signer using another key or altering the tx
+        signature
+            .verify(tx.sighash(), self.address)
+            .map_err(|e| SignerError::Remote(format!("invalid tx signature: {e}")))?;
+
+        // typed txs carry just the y parity, the local wallet hands out EIP-155 `v`s instead
+        if signature.v <= 1 {
+            signature.v = to_eip155_v(signature.v as u8, chain_id);
+        }
+
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{Eip1559TransactionRequest, H160};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::test_utils::{spawn_rpc_server, TEST_KEY};
+
+    #[tokio::test]
+    async fn remote_signer_produces_the_same_signature_as_the_key() {
+        // given
+        let wallet = LocalWallet::from_str(TEST_KEY)
+            .unwrap()
+            .with_chain_id(Chain::AnvilHardhat);
+        let url = spawn_signer_server(wallet.clone()).await;
+        let signer = L1Signer::remote(url, wallet.address(), Chain::AnvilHardhat);
+
+        let tx = given_tx();
+
+        // when
+        let signature = signer.sign_transaction(&tx).await.unwrap();
+
+        // then
+        assert_eq!(signature, wallet.sign_transaction(&tx).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn remote_signer_rejects_signature_by_another_key() {
+        // given
+        let wallet = LocalWallet::from_str(TEST_KEY)
+            .unwrap()
+            .with_chain_id(Chain::AnvilHardhat);
+        let url = spawn_signer_server(wallet).await;
+        let signer = L1Signer::remote(url, H160::random(), Chain::AnvilHardhat);
+
+        // when
+        let result = signer.sign_transaction(&given_tx()).await;
+
+        // then
+        let Err(SignerError::Remote(msg)) = result else {
+            panic!("expected a remote signer error, got: {result:?}");
+        };
+        assert!(msg.starts_with("invalid tx signature"));
+    }
+
+    #[test]
+    fn can_decrypt_keystore() {
+        // given
+        let dir = TempDir::new().unwrap();
+        let (wallet, name) =
+            LocalWallet::new_keystore(&dir, &mut ethers::core::rand::thread_rng(), "pass", None)
+                .unwrap();
+
+        // when
+        let signer =
+            L1Signer::keystore(&dir.path().join(name), "pass", Chain::AnvilHardhat).unwrap();
+
+        // then
+        assert_eq!(signer.address(), wallet.address());
+        assert_eq!(signer.chain_id(), Chain::AnvilHardhat as u64);
+    }
+
+    #[test]
+    fn wrong_keystore_password_causes_an_error() {
+        // given
+        let dir = TempDir::new().unwrap();
+        let (_, name) =
+            LocalWallet::new_keystore(&dir, &mut ethers::core::rand::thread_rng(), "pass", None)
+                .unwrap();
+        let path = dir.path().join(name);
+
+        // when
+        let result = L1Signer::keystore(&path, "wrong", Chain::AnvilHardhat);
+
+        // then
+        let Err(ports::l1::Error::Other(msg)) = result else {
+            panic!("expected an error");
+        };
+        assert!(msg.starts_with(&format!("could not decrypt keystore `{}`", path.display())));
+    }
+
+    fn given_tx() -> TypedTransaction {
+        Eip1559TransactionRequest::new()
+            .to(H160::random())
+            .nonce(3)
+            .gas(21_000)
+            .max_fee_per_gas(2)
+            .max_priority_fee_per_gas(1)
+            .chain_id(Chain::AnvilHardhat as u64)
+            .into()
+    }
+
+    /// Stands in for a signing service by answering `eth_signTransaction` with `wallet`.
+    async fn spawn_signer_server(wallet: LocalWallet) -> Url {
+        spawn_rpc_server(move |_, params| {
+            // like a real signer, sign for the chain it's configured with
+            let mut tx: TypedTransaction = serde_json::from_value(params[0].clone()).unwrap();
+            tx.set_chain_id(wallet.chain_id());
+            let signature = wallet.sign_transaction_sync(&tx).unwrap();
+
+            serde_json::to_value(tx.rlp_signed(&signature)).unwrap()
+        })
+        .await
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use ethers::types::{Address, Chain};
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+use url::Url;
+
+use crate::{rpc::RpcConnection, L1Signer};
+
+/// The private key the test transactions are signed with.
+pub(crate) const TEST_KEY: &str =
+    "0x9e56ccf010fa4073274b8177ccaad46fbaf286645310d03ac9bb6afa922a7c36";
+
+pub(crate) fn given_signer() -> L1Signer {
+    L1Signer::local(TEST_KEY, Chain::AnvilHardhat).unwrap()
+}
+
+/// Connects to the contract at the zero address through `url`, committing every block.
+pub(crate) async fn given_connection(url: &Url) -> RpcConnection {
+    RpcConnection::connect(
+        url,
+        Address::zero(),
+        given_signer(),
+        1.try_into().unwrap(),
+        1000.try_into().unwrap(),
+    )
+    .await
+    .unwrap()
+}
+
+/// Serves JSON-RPC over HTTP, answering each request with the result `handler` returns for its
+/// method and params.
+pub(crate) async fn spawn_rpc_server<F>(handler: F) -> Url
+where
+    F: Fn(&str, &Value) -> Value + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    serve_rpc(listener, handler);
+
+    url.parse().unwrap()
+}
+
+/// Like `spawn_rpc_server`, serving on an already bound `listener`.
+pub(crate) fn serve_rpc<F>(listener: TcpListener, handler: F)
+where
+    F: Fn(&str, &Value) -> Value + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let handler = Arc::clone(&handler);
+            tokio::spawn(async move {
+                let mut stream = BufReader::new(stream);
+
+                // connections are kept alive, so keep answering until the client hangs up
+                while let Some(request) = read_request(&mut stream).await {
+                    let method = request["method"].as_str().unwrap_or_default();
+                    let response = json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": handler(method, &request["params"]),
+                    })
+                    .to_string();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{response}",
+                        response.len()
+                    );
+                    if stream.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+}
+
+async fn read_request<S>(stream: &mut BufReader<S>) -> Option<Value>
+where
+    S: tokio::io::AsyncRead + Unpin,
+{
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end().to_lowercase();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(length) = line.strip_prefix("content-length:") {
+            content_length = length.trim().parse().ok()?;
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).await.ok()?;
+    serde_json::from_slice(&body).ok()
+}
@@ -1,78 +1,75 @@
-use fuel_core_client::client::{types::Block, FuelClient as GqlClient};
+use fuel_core_client::client::types::Block;
+use fuel_core_types::fuel_tx::Transaction;
 use metrics::{
     prometheus::core::Collector, ConnectionHealthTracker, HealthChecker, RegistersMetrics,
 };
 use url::Url;
 
-use crate::{metrics::Metrics, Error, Result};
+use self::{
+    connection::GqlConnection,
+    health_tracking_middleware::{FuelGqlApi, HealthTrackingMiddleware},
+};
+use crate::{metrics::Metrics, Result};
+
+mod connection;
+mod health_tracking_middleware;
 
+#[derive(Clone)]
 pub struct HttpClient {
-    client: GqlClient,
-    metrics: Metrics,
-    health_tracker: ConnectionHealthTracker,
+    inner: HealthTrackingMiddleware<GqlConnection>,
 }
 
 impl HttpClient {
     #[must_use]
     pub fn new(url: &Url, unhealthy_after_n_errors: usize) -> Self {
-        let client = GqlClient::new(url).expect("Url to be well formed");
-        Self {
-            client,
-            metrics: Metrics::default(),
-            health_tracker: ConnectionHealthTracker::new(unhealthy_after_n_errors),
-        }
+        Self::with_metrics(url, unhealthy_after_n_errors, Metrics::default())
+    }
+
+    pub(crate) fn with_metrics(
+        url: &Url,
+        unhealthy_after_n_errors: usize,
+        metrics: Metrics,
+    ) -> Self {
+        let inner = HealthTrackingMiddleware::new(
+            GqlConnection::new(url),
+            unhealthy_after_n_errors,
+            metrics,
+        );
+        Self { inner }
     }
 
     #[cfg(feature = "test-helpers")]
     pub async fn produce_blocks(&self, num: u32) -> Result<()> {
-        self.client
-            .produce_blocks(num, None)
-            .await
-            .map_err(|e| Error::Network(e.to_string()))?;
-
-        Ok(())
+        self.inner.adapter().produce_blocks(num).await
     }
 
     pub(crate) async fn _block_at_height(&self, height: u32) -> Result<Option<Block>> {
-        let maybe_block = self
-            .client
-            .block_by_height(height.into())
-            .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+        self.inner.block(height).await
+    }
 
-        Ok(maybe_block.map(Into::into))
+    pub(crate) async fn _full_block_at_height(
+        &self,
+        height: u32,
+    ) -> Result<Option<(Block, Vec<Transaction>)>> {
+        self.inner.full_block(height).await
     }
 
     pub(crate) async fn _latest_block(&self) -> Result<Block> {
-        match self.client.chain_info().await {
-            Ok(chain_info) => {
-                self.handle_network_success();
-                Ok(chain_info.latest_block)
-            }
-            Err(err) => {
-                self.handle_network_error();
-                Err(Error::Network(err.to_string()))
-            }
-        }
+        self.inner.chain().await
     }
 
     #[must_use]
     pub fn connection_health_checker(&self) -> HealthChecker {
-        self.health_tracker.tracker()
-    }
-
-    fn handle_network_error(&self) {
-        self.health_tracker.note_failure();
-        self.metrics.fuel_network_errors.inc();
+        self.inner.connection_health_checker()
     }
 
-    fn handle_network_success(&self) {
-        self.health_tracker.note_success();
+    pub(crate) fn health_tracker(&self) -> ConnectionHealthTracker {
+        self.inner.health_tracker()
     }
 }
 
 impl RegistersMetrics for HttpClient {
     fn metrics(&self) -> Vec<Box<dyn Collector>> {
-        self.metrics.metrics()
+        self.inner.metrics()
     }
 }
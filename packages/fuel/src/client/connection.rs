@@ -0,0 +1,148 @@
+use cynic::QueryBuilder;
+use fuel_core_client::client::{
+    schema::{
+        block::{Block as BlockFragment, BlockByHeightArgs},
+        tx::TransactionIdFragment,
+        U32,
+    },
+    types::Block,
+    FuelClient as GqlClient,
+};
+use fuel_core_types::{fuel_tx::Transaction, fuel_types::canonical::Deserialize};
+use url::Url;
+
+use self::query::FullBlockByHeightQuery;
+use super::health_tracking_middleware::FuelGqlApi;
+use crate::{Error, Result};
+
+/// Sends the GraphQL requests to a fuel node as they are, see `HealthTrackingMiddleware` for
+/// the bookkeeping around them.
+#[derive(Clone)]
+pub(crate) struct GqlConnection {
+    client: GqlClient,
+}
+
+impl GqlConnection {
+    pub(crate) fn new(url: &Url) -> Self {
+        let client = GqlClient::new(url).expect("Url to be well formed");
+        Self { client }
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub(crate) async fn produce_blocks(&self, num: u32) -> Result<()> {
+        self.client
+            .produce_blocks(num, None)
+            .await
+            .map_err(request_error)?;
+
+        Ok(())
+    }
+}
+
+fn request_error(err: std::io::Error) -> Error {
+    match err.kind() {
+        std::io::ErrorKind::TimedOut => Error::Timeout(Box::new(err)),
+        std::io::ErrorKind::InvalidData => Error::InvalidData(Box::new(err)),
+        _ => Error::Network(err.to_string()),
+    }
+}
+
+#[async_trait::async_trait]
+impl FuelGqlApi for GqlConnection {
+    async fn chain(&self) -> Result<Block> {
+        let chain_info = self.client.chain_info().await.map_err(request_error)?;
+
+        Ok(chain_info.latest_block)
+    }
+
+    async fn block(&self, height: u32) -> Result<Option<Block>> {
+        self.client
+            .block_by_height(height.into())
+            .await
+            .map_err(request_error)
+    }
+
+    async fn full_block(&self, height: u32) -> Result<Option<(Block, Vec<Transaction>)>> {
+        let query = FullBlockByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(height)),
+        });
+        let Some(full_block) = self.client.query(query).await.map_err(request_error)?.block else {
+            return Ok(None);
+        };
+
+        let transactions = full_block
+            .transactions
+            .iter()
+            .map(|tx| {
+                Transaction::from_bytes(&tx.raw_payload.0 .0).map_err(|e| {
+                    Error::InvalidData(
+                        format!("transaction {} of block at height {height}: {e:?}", tx.id.0)
+                            .into(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let block: Block = BlockFragment {
+            id: full_block.id,
+            header: full_block.header,
+            consensus: full_block.consensus,
+            transactions: full_block
+                .transactions
+                .into_iter()
+                .map(|tx| TransactionIdFragment { id: tx.id })
+                .collect(),
+        }
+        .into();
+
+        if usize::from(block.header.transactions_count) != transactions.len() {
+            return Err(Error::InvalidData(
+                format!(
+                    "block at height {height} holds {} transactions, {} were served",
+                    block.header.transactions_count,
+                    transactions.len()
+                )
+                .into(),
+            ));
+        }
+
+        Ok(Some((block, transactions)))
+    }
+}
+
+// kept apart as the derived code can't have the crate's `Result` in scope
+mod query {
+    use fuel_core_client::client::schema::{
+        block::{BlockByHeightArgs, Consensus, Header},
+        schema, BlockId, HexString, TransactionId,
+    };
+
+    /// `block` along with the payload of its transactions, which the client only offers to fetch
+    /// one transaction at a time.
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockByHeightArgs"
+    )]
+    pub(super) struct FullBlockByHeightQuery {
+        #[arguments(height: $height)]
+        pub(super) block: Option<FullBlock>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "assets/schema.sdl", graphql_type = "Block")]
+    pub(super) struct FullBlock {
+        pub(super) id: BlockId,
+        pub(super) header: Header,
+        pub(super) consensus: Consensus,
+        pub(super) transactions: Vec<RawTransaction>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "assets/schema.sdl", graphql_type = "Transaction")]
+    pub(super) struct RawTransaction {
+        pub(super) id: TransactionId,
+        pub(super) raw_payload: HexString,
+    }
+}
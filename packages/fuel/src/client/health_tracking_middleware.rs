@@ -0,0 +1,191 @@
+use std::time::Instant;
+
+use ::metrics::{
+    prometheus::core::Collector, ConnectionHealthTracker, HealthChecker, RegistersMetrics,
+};
+use fuel_core_client::client::types::Block;
+use fuel_core_types::fuel_tx::Transaction;
+
+use crate::{metrics::Metrics, Error, Result};
+
+/// The GraphQL operations the committer runs against a fuel node, named after their root field.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub(crate) trait FuelGqlApi: Send + Sync {
+    /// The latest block, as reported in the chain info.
+    async fn chain(&self) -> Result<Block>;
+    async fn block(&self, height: u32) -> Result<Option<Block>>;
+    /// The block along with its transactions.
+    async fn full_block(&self, height: u32) -> Result<Option<(Block, Vec<Transaction>)>>;
+}
+
+/// Times every request and notes whether it reached the node, for the health of the connection
+/// and for metrics labelled by the GraphQL operation.
+#[derive(Clone)]
+pub(crate) struct HealthTrackingMiddleware<T> {
+    adapter: T,
+    metrics: Metrics,
+    health_tracker: ConnectionHealthTracker,
+}
+
+impl<T> HealthTrackingMiddleware<T> {
+    pub(crate) fn new(adapter: T, unhealthy_after_n_errors: usize, metrics: Metrics) -> Self {
+        Self {
+            adapter,
+            metrics,
+            health_tracker: ConnectionHealthTracker::new(unhealthy_after_n_errors),
+        }
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub(crate) fn adapter(&self) -> &T {
+        &self.adapter
+    }
+
+    pub(crate) fn connection_health_checker(&self) -> HealthChecker {
+        self.health_tracker.tracker()
+    }
+
+    pub(crate) fn health_tracker(&self) -> ConnectionHealthTracker {
+        self.health_tracker.clone()
+    }
+
+    fn note_response<K>(&self, operation: &str, started: Instant, response: &Result<K>) {
+        self.metrics
+            .fuel_request_duration
+            .with_label_values(&[operation])
+            .observe(started.elapsed().as_secs_f64());
+
+        match response {
+            Ok(_) => {
+                self.health_tracker.note_success();
+            }
+            Err(Error::Network(..) | Error::Timeout(..)) => {
+                self.metrics
+                    .fuel_network_errors
+                    .with_label_values(&[operation])
+                    .inc();
+                self.health_tracker.note_failure();
+            }
+            _ => {}
+        }
+    }
+}
+
+// User responsible for registering any metrics T might have
+impl<T> RegistersMetrics for HealthTrackingMiddleware<T> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        self.metrics.metrics()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> FuelGqlApi for HealthTrackingMiddleware<T>
+where
+    T: FuelGqlApi,
+{
+    async fn chain(&self) -> Result<Block> {
+        let started = Instant::now();
+        let response = self.adapter.chain().await;
+        self.note_response("chain", started, &response);
+        response
+    }
+
+    async fn block(&self, height: u32) -> Result<Option<Block>> {
+        let started = Instant::now();
+        let response = self.adapter.block(height).await;
+        self.note_response("block", started, &response);
+        response
+    }
+
+    async fn full_block(&self, height: u32) -> Result<Option<(Block, Vec<Transaction>)>> {
+        let started = Instant::now();
+        let response = self.adapter.full_block(height).await;
+        self.note_response("full_block", started, &response);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::metrics::prometheus::{
+        proto::{Metric, MetricFamily},
+        Registry,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_network_errors_per_operation() {
+        // given
+        let mut adapter = MockFuelGqlApi::new();
+        adapter
+            .expect_block()
+            .returning(|_| Err(Error::Network("unreachable".to_string())));
+        adapter.expect_full_block().returning(|_| Ok(None));
+
+        let middleware = HealthTrackingMiddleware::new(adapter, 2, Metrics::default());
+        let health_check = middleware.connection_health_checker();
+
+        let registry = Registry::default();
+        middleware.register_metrics(&registry);
+
+        // when
+        let _ = middleware.block(1).await;
+        let _ = middleware.full_block(1).await;
+        let _ = middleware.block(1).await;
+        let _ = middleware.block(1).await;
+
+        // then
+        assert!(!health_check.healthy());
+
+        let metrics = registry.gather();
+        let errors = metric_labelled(&metrics, "fuel_network_errors", "block").get_counter();
+        assert_eq!(errors.get_value(), 3f64);
+    }
+
+    #[tokio::test]
+    async fn records_the_duration_of_every_request() {
+        // given
+        let mut adapter = MockFuelGqlApi::new();
+        adapter.expect_full_block().returning(|_| Ok(None));
+        adapter
+            .expect_block()
+            .returning(|_| Err(Error::Network("unreachable".to_string())));
+
+        let middleware = HealthTrackingMiddleware::new(adapter, 1, Metrics::default());
+
+        let registry = Registry::default();
+        middleware.register_metrics(&registry);
+
+        // when
+        let _ = middleware.full_block(1).await;
+        let _ = middleware.full_block(1).await;
+        let _ = middleware.block(1).await;
+
+        // then
+        let metrics = registry.gather();
+        let durations = |operation| {
+            metric_labelled(&metrics, "fuel_request_duration_seconds", operation)
+                .get_histogram()
+                .get_sample_count()
+        };
+        assert_eq!(durations("full_block"), 2);
+        assert_eq!(durations("block"), 1);
+    }
+
+    fn metric_labelled<'a>(metrics: &'a [MetricFamily], name: &str, operation: &str) -> &'a Metric {
+        metrics
+            .iter()
+            .find(|metric| metric.get_name() == name)
+            .and_then(|metric| {
+                metric.get_metric().iter().find(|metric| {
+                    metric
+                        .get_label()
+                        .iter()
+                        .any(|label| label.get_value() == operation)
+                })
+            })
+            .unwrap()
+    }
+}
@@ -1,10 +1,13 @@
 #![deny(unused_crate_dependencies)]
 use fuel_core_client::client::types::Block;
-use ports::types::FuelBlock;
+use fuel_core_types::fuel_types::canonical::Serialize;
+use ports::types::{FuelBlock, FuelBlockHeader};
 mod client;
 mod metrics;
+mod multi_node;
 
 pub use client::*;
+pub use multi_node::MultiNodeClient;
 
 type Error = ports::fuel::Error;
 type Result<T> = ports::fuel::Result<T>;
@@ -16,6 +19,82 @@ fn convert_block(block: Block) -> FuelBlock {
     }
 }
 
+fn convert_header(block: Block) -> FuelBlockHeader {
+    let producer = block.block_producer().map(|producer| **producer);
+    let header = &block.header;
+
+    FuelBlockHeader {
+        block: FuelBlock {
+            hash: *block.id,
+            height: header.height,
+        },
+        prev_root: *header.prev_root,
+        time: header.time.0,
+        da_height: header.da_height,
+        transactions_root: *header.transactions_root,
+        message_outbox_root: *header.message_outbox_root,
+        application_hash: *header.application_hash,
+        producer,
+        transactions_count: header.transactions_count,
+    }
+}
+
+/// Lays out the block id and the full header, followed by the transactions. The application and
+/// the consensus header are laid out just like fuel hashes them, so that hashing the former gives
+/// the `application_hash` and hashing the latter gives the block id. All numbers are big-endian.
+///
+/// | bytes | field                               |
+/// |-------|-------------------------------------|
+/// | 32    | block id                            |
+/// | 8     | `da_height`                         |
+/// | 4     | `consensus_parameters_version`      |
+/// | 4     | `state_transition_bytecode_version` |
+/// | 2     | `transactions_count`                |
+/// | 4     | `message_receipt_count`             |
+/// | 32    | `transactions_root`                 |
+/// | 32    | `message_outbox_root`               |
+/// | 32    | `event_inbox_root`                  |
+/// | 32    | `prev_root`                         |
+/// | 4     | `height`                            |
+/// | 8     | `time`, as TAI64                    |
+/// | 32    | `application_hash`                  |
+/// | 4     | number of transactions              |
+///
+/// Every transaction then follows as its `u32` length and canonical serialization, in the order
+/// of the block.
+fn encode_block_data(
+    block: &Block,
+    transactions: &[fuel_core_types::fuel_tx::Transaction],
+) -> Vec<u8> {
+    let header = &block.header;
+
+    let mut data = vec![];
+    data.extend_from_slice(&*block.id);
+
+    data.extend_from_slice(&header.da_height.to_be_bytes());
+    data.extend_from_slice(&header.consensus_parameters_version.to_be_bytes());
+    data.extend_from_slice(&header.state_transition_bytecode_version.to_be_bytes());
+    data.extend_from_slice(&header.transactions_count.to_be_bytes());
+    data.extend_from_slice(&header.message_receipt_count.to_be_bytes());
+    data.extend_from_slice(&*header.transactions_root);
+    data.extend_from_slice(&*header.message_outbox_root);
+    data.extend_from_slice(&*header.event_inbox_root);
+
+    data.extend_from_slice(&*header.prev_root);
+    data.extend_from_slice(&header.height.to_be_bytes());
+    data.extend_from_slice(&header.time.0.to_be_bytes());
+    data.extend_from_slice(&*header.application_hash);
+
+    data.extend_from_slice(&(transactions.len() as u32).to_be_bytes());
+    for tx in transactions {
+        let bytes = tx.to_bytes();
+        data.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        data.extend_from_slice(&bytes);
+    }
+
+    data
+}
+
 #[async_trait::async_trait]
 impl ports::fuel::Api for client::HttpClient {
     async fn block_at_height(&self, height: u32) -> ports::fuel::Result<Option<FuelBlock>> {
@@ -26,6 +105,27 @@ impl ports::fuel::Api for client::HttpClient {
         let block = self._latest_block().await?;
         Ok(convert_block(block))
     }
+
+    async fn block_header_at_height(
+        &self,
+        height: u32,
+    ) -> ports::fuel::Result<Option<FuelBlockHeader>> {
+        Ok(self._block_at_height(height).await?.map(convert_header))
+    }
+
+    async fn block_data(&self, height: u32) -> ports::fuel::Result<Option<Vec<u8>>> {
+        let Some((block, transactions)) = self._full_block_at_height(height).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(encode_block_data(&block, &transactions)))
+    }
+
+    // fuel-core (as of 0.26) only offers subscriptions to the status of a given transaction,
+    // there is no way of being notified about new blocks yet
+    async fn subscribe_to_blocks(&self) -> ports::fuel::Result<Option<ports::fuel::BlockStream>> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
@@ -34,6 +134,16 @@ mod tests {
         prometheus::{proto::Metric, Registry},
         RegistersMetrics,
     };
+    use fuel_core_client::client::types::block::{Consensus, Header};
+    use fuel_core_types::{
+        blockchain::header::{
+            ApplicationHeader, ConsensusHeader, GeneratedApplicationFields,
+            GeneratedConsensusFields,
+        },
+        fuel_crypto::Hasher,
+        fuel_tx::{policies::Policies, Bytes32, Transaction},
+        tai64::Tai64,
+    };
     use ports::fuel::Api;
     use url::Url;
 
@@ -92,6 +202,56 @@ mod tests {
     //     assert_eq!(result.height, 3);
     // }
 
+    #[test]
+    fn block_data_can_be_checked_against_the_block_id() {
+        // given
+        let application = ApplicationHeader {
+            da_height: 1u64.into(),
+            consensus_parameters_version: 2,
+            state_transition_bytecode_version: 3,
+            generated: GeneratedApplicationFields {
+                transactions_count: 1,
+                message_receipt_count: 4,
+                transactions_root: [5; 32].into(),
+                message_outbox_root: [6; 32].into(),
+                event_inbox_root: [7; 32].into(),
+            },
+        };
+        let consensus = ConsensusHeader {
+            prev_root: [8; 32].into(),
+            height: 9u32.into(),
+            time: Tai64(10),
+            generated: GeneratedConsensusFields {
+                application_hash: application.hash(),
+            },
+        };
+        let block = given_a_block(&application, &consensus);
+        let transaction: Transaction = Transaction::script(
+            1,
+            vec![2],
+            vec![3],
+            Policies::default(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .into();
+
+        // when
+        let data = encode_block_data(&block, std::slice::from_ref(&transaction));
+
+        // then
+        let id: Bytes32 = consensus.hash().into();
+        assert_eq!(data[..32], *id);
+        assert_eq!(Hasher::hash(&data[32..150]), application.hash());
+        assert_eq!(Hasher::hash(&data[150..226]), id);
+
+        let tx_bytes = transaction.to_bytes();
+        assert_eq!(data[226..230], 1u32.to_be_bytes());
+        assert_eq!(data[230..234], (tx_bytes.len() as u32).to_be_bytes());
+        assert_eq!(data[234..], tx_bytes);
+    }
+
     #[tokio::test]
     async fn updates_metrics_in_case_of_network_err() {
         // temporary 'fake' address to cause a network error the same effect will be achieved by
@@ -139,4 +299,32 @@ mod tests {
         let _ = fuel_adapter.latest_block().await;
         assert!(!health_check.healthy());
     }
+
+    fn given_a_block(
+        application: &ApplicationHeader<GeneratedApplicationFields>,
+        consensus: &ConsensusHeader<GeneratedConsensusFields>,
+    ) -> Block {
+        let id = consensus.hash().into();
+        Block {
+            id,
+            header: Header {
+                id,
+                da_height: *application.da_height,
+                consensus_parameters_version: application.consensus_parameters_version,
+                state_transition_bytecode_version: application.state_transition_bytecode_version,
+                transactions_count: application.generated.transactions_count,
+                message_receipt_count: application.generated.message_receipt_count,
+                transactions_root: application.generated.transactions_root,
+                message_outbox_root: application.generated.message_outbox_root,
+                event_inbox_root: application.generated.event_inbox_root,
+                height: *consensus.height,
+                prev_root: consensus.prev_root,
+                time: consensus.time,
+                application_hash: consensus.generated.application_hash,
+            },
+            consensus: Consensus::Unknown,
+            transactions: vec![],
+            block_producer: None,
+        }
+    }
 }
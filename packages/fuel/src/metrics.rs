@@ -1,27 +1,46 @@
 use metrics::{
-    prometheus::{core::Collector, IntCounter, Opts},
+    prometheus::{core::Collector, HistogramOpts, HistogramVec, IntCounterVec, Opts},
     RegistersMetrics,
 };
 
+#[derive(Clone)]
 pub struct Metrics {
-    pub fuel_network_errors: IntCounter,
+    pub fuel_network_errors: IntCounterVec,
+    pub fuel_request_duration: HistogramVec,
 }
 
 impl RegistersMetrics for Metrics {
     fn metrics(&self) -> Vec<Box<dyn Collector>> {
-        vec![Box::new(self.fuel_network_errors.clone())]
+        vec![
+            Box::new(self.fuel_network_errors.clone()),
+            Box::new(self.fuel_request_duration.clone()),
+        ]
     }
 }
 
 impl Default for Metrics {
     fn default() -> Self {
-        let fuel_network_errors = IntCounter::with_opts(Opts::new(
-            "fuel_network_errors",
-            "Number of network errors encountered while polling for a new Fuel block.",
-        ))
+        let fuel_network_errors = IntCounterVec::new(
+            Opts::new(
+                "fuel_network_errors",
+                "Number of network errors encountered while querying the Fuel node.",
+            ),
+            &["operation"],
+        )
         .expect("fuel_network_errors metric to be correctly configured");
+
+        let fuel_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "fuel_request_duration_seconds",
+                "Time taken by the GraphQL requests sent to the Fuel node.",
+            ),
+            &["operation"],
+        )
+        .expect("fuel_request_duration_seconds metric to be correctly configured");
+
         Self {
             fuel_network_errors,
+            fuel_request_duration,
         }
     }
 }
@@ -0,0 +1,470 @@
+use std::{future::Future, sync::Arc};
+
+use ::metrics::{
+    endpoint_name,
+    prometheus::{core::Collector, IntCounter, IntGaugeVec, Opts},
+    ConnectionHealthTracker, HealthCheck, HealthChecker, RegistersMetrics,
+};
+use futures::future::join_all;
+use ports::{
+    fuel::BlockStream,
+    types::{FuelBlock, FuelBlockHeader},
+};
+use tracing::{error, warn};
+use url::Url;
+
+use crate::{client::HttpClient, metrics::Metrics as ClientMetrics, Error, Result};
+
+/// A fuel node together with the health of the requests sent to it.
+struct Node<N> {
+    name: String,
+    api: N,
+    health_tracker: ConnectionHealthTracker,
+}
+
+/// Asks every fuel node at once, so that reads go on while some nodes are unreachable and a node
+/// serving other blocks than the rest is noticed rather than trusted. Nodes are listed in order
+/// of priority, requests that aren't fanned out go to the first node able to answer them.
+#[derive(Clone)]
+pub struct MultiNodeClient<N = HttpClient> {
+    nodes: Arc<[Node<N>]>,
+    client_metrics: ClientMetrics,
+    metrics: Metrics,
+}
+
+impl MultiNodeClient {
+    #[must_use]
+    pub fn new(urls: &[Url], unhealthy_after_n_errors: usize) -> Self {
+        // the network errors of all the nodes are counted together
+        let client_metrics = ClientMetrics::default();
+        let nodes = urls
+            .iter()
+            .map(|url| {
+                let client =
+                    HttpClient::with_metrics(url, unhealthy_after_n_errors, client_metrics.clone());
+                let health_tracker = client.health_tracker();
+                (endpoint_name(url), client, health_tracker)
+            })
+            .collect();
+
+        Self::from_nodes(nodes, client_metrics)
+    }
+}
+
+impl<N> MultiNodeClient<N> {
+    fn from_nodes(
+        nodes: Vec<(String, N, ConnectionHealthTracker)>,
+        client_metrics: ClientMetrics,
+    ) -> Self {
+        let nodes: Arc<[Node<N>]> = nodes
+            .into_iter()
+            .map(|(name, api, health_tracker)| Node {
+                name,
+                api,
+                health_tracker,
+            })
+            .collect();
+
+        let metrics = Metrics::default();
+        for node in nodes.iter() {
+            metrics.note_health(node);
+        }
+
+        Self {
+            nodes,
+            client_metrics,
+            metrics,
+        }
+    }
+
+    /// Healthy as long as any of the nodes is.
+    #[must_use]
+    pub fn connection_health_checker(&self) -> HealthChecker {
+        Box::new(AnyHealthy(
+            self.nodes
+                .iter()
+                .map(|node| node.health_tracker.clone())
+                .collect(),
+        ))
+    }
+
+    #[must_use]
+    pub fn node_health_checkers(&self) -> Vec<(String, HealthChecker)> {
+        self.nodes
+            .iter()
+            .map(|node| (node.name.clone(), node.health_tracker.tracker()))
+            .collect()
+    }
+
+    async fn ask_all<'a, F, Fut, T>(&'a self, request: F) -> Vec<(&'a str, Result<T>)>
+    where
+        F: Fn(&'a N) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let responses = join_all(self.nodes.iter().map(|node| request(&node.api))).await;
+
+        self.nodes
+            .iter()
+            .zip(responses)
+            .map(|(node, response)| {
+                self.metrics.note_health(node);
+                if let Err(err) = &response {
+                    warn!("fuel node {} left out: {err}", node.name);
+                }
+
+                (node.name.as_str(), response)
+            })
+            .collect()
+    }
+
+    /// What the reachable nodes hold at `height`, as long as they all hold the same block.
+    /// Nodes that don't have the block yet are fine, they may just be lagging behind.
+    fn agreed<T: Copy>(
+        &self,
+        height: u32,
+        responses: Vec<(&str, Result<Option<T>>)>,
+        block_of: impl Fn(&T) -> FuelBlock,
+    ) -> Result<Option<T>> {
+        let mut held = vec![];
+        let mut errors = vec![];
+        for (name, response) in responses {
+            match response {
+                Ok(Some(value)) => held.push((name, value)),
+                Ok(None) => {}
+                Err(err) => errors.push(format!("{name}: {err}")),
+            }
+        }
+
+        if errors.len() == self.nodes.len() {
+            return Err(Error::Network(format!(
+                "none of the fuel nodes could be reached: {}",
+                errors.join(", ")
+            )));
+        }
+
+        let Some((_, first)) = held.first() else {
+            return Ok(None);
+        };
+
+        let hash = block_of(first).hash;
+        if held.iter().any(|(_, value)| block_of(value).hash != hash) {
+            let reported = held
+                .iter()
+                .map(|(name, value)| format!("{name}: 0x{}", hex::encode(block_of(value).hash)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            error!("fuel nodes hold different blocks at height {height}: {reported}");
+            self.metrics.fuel_node_disagreements.inc();
+
+            return Err(Error::Inconsistent(format!(
+                "different blocks at height {height}: {reported}"
+            )));
+        }
+
+        Ok(Some(*first))
+    }
+}
+
+#[async_trait::async_trait]
+impl<N: ports::fuel::Api> ports::fuel::Api for MultiNodeClient<N> {
+    async fn block_at_height(&self, height: u32) -> Result<Option<FuelBlock>> {
+        let responses = self.ask_all(|api| api.block_at_height(height)).await;
+        self.agreed(height, responses, |block| *block)
+    }
+
+    /// The block at the highest height every reachable node has got to.
+    async fn latest_block(&self) -> Result<FuelBlock> {
+        let responses = self.ask_all(|api| api.latest_block()).await;
+
+        let mut errors = vec![];
+        let mut heights = vec![];
+        for (name, response) in responses {
+            match response {
+                Ok(block) => heights.push(block.height),
+                Err(err) => errors.push(format!("{name}: {err}")),
+            }
+        }
+
+        let Some(height) = heights.into_iter().min() else {
+            return Err(Error::Network(format!(
+                "none of the fuel nodes could be reached: {}",
+                errors.join(", ")
+            )));
+        };
+
+        self.block_at_height(height).await?.ok_or_else(|| {
+            Error::Network(format!(
+                "fuel nodes reported a latest height of at least {height} but didn't serve its block"
+            ))
+        })
+    }
+
+    async fn block_header_at_height(&self, height: u32) -> Result<Option<FuelBlockHeader>> {
+        let responses = self.ask_all(|api| api.block_header_at_height(height)).await;
+        self.agreed(height, responses, |header| header.block)
+    }
+
+    async fn block_data(&self, height: u32) -> Result<Option<Vec<u8>>> {
+        let mut errors = vec![];
+        for node in self.nodes.iter() {
+            let response = node.api.block_data(height).await;
+            self.metrics.note_health(node);
+
+            match response {
+                Ok(Some(data)) => return Ok(Some(data)),
+                Ok(None) => {}
+                Err(err) => {
+                    warn!("failing over from fuel node {}: {err}", node.name);
+                    errors.push(format!("{}: {err}", node.name));
+                }
+            }
+        }
+
+        // the block is only missing if some node could tell so
+        if errors.len() == self.nodes.len() {
+            return Err(Error::Network(format!(
+                "none of the fuel nodes could be reached: {}",
+                errors.join(", ")
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Follows the first node, in order of priority, able to push new blocks.
+    async fn subscribe_to_blocks(&self) -> Result<Option<BlockStream>> {
+        for node in self.nodes.iter() {
+            let response = node.api.subscribe_to_blocks().await;
+            self.metrics.note_health(node);
+
+            match response {
+                Ok(Some(blocks)) => return Ok(Some(blocks)),
+                Ok(None) => {}
+                Err(err) => warn!(
+                    "cannot subscribe to blocks of fuel node {}: {err}",
+                    node.name
+                ),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<N> RegistersMetrics for MultiNodeClient<N> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        let mut metrics = self.client_metrics.metrics();
+        metrics.push(Box::new(self.metrics.fuel_node_healthy.clone()));
+        metrics.push(Box::new(self.metrics.fuel_node_disagreements.clone()));
+        metrics
+    }
+}
+
+struct AnyHealthy(Vec<ConnectionHealthTracker>);
+
+impl HealthCheck for AnyHealthy {
+    fn healthy(&self) -> bool {
+        self.0.iter().any(HealthCheck::healthy)
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    fuel_node_healthy: IntGaugeVec,
+    fuel_node_disagreements: IntCounter,
+}
+
+impl Metrics {
+    fn note_health<N>(&self, node: &Node<N>) {
+        self.fuel_node_healthy
+            .with_label_values(&[&node.name])
+            .set(i64::from(node.health_tracker.healthy()));
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let fuel_node_healthy = IntGaugeVec::new(
+            Opts::new(
+                "fuel_node_healthy",
+                "Whether the Fuel node is healthy (1) or not (0).",
+            ),
+            &["endpoint"],
+        )
+        .expect("fuel_node_healthy metric to be correctly configured");
+
+        let fuel_node_disagreements = IntCounter::with_opts(Opts::new(
+            "fuel_node_disagreements",
+            "Number of times the Fuel nodes served different blocks at the same height.",
+        ))
+        .expect("fuel_node_disagreements metric to be correctly configured");
+
+        Self {
+            fuel_node_healthy,
+            fuel_node_disagreements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::metrics::prometheus::{proto::Metric, Registry};
+    use futures::StreamExt;
+    use mockall::predicate::eq;
+    use ports::fuel::{Api, MockApi};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn latest_block_is_the_highest_every_reachable_node_has() {
+        // given
+        let block = given_a_block(5, 1);
+        let client = given_client(vec![
+            given_node_at(given_a_block(7, 2), vec![block]),
+            given_node_at(block, vec![block]),
+            given_unreachable_node(),
+        ]);
+
+        // when
+        let latest = client.latest_block().await.unwrap();
+
+        // then
+        assert_eq!(latest, block);
+    }
+
+    #[tokio::test]
+    async fn rejects_blocks_the_nodes_disagree_on() {
+        // given
+        let client = given_client(vec![
+            given_node_at(given_a_block(5, 1), vec![given_a_block(5, 1)]),
+            given_node_at(given_a_block(5, 2), vec![given_a_block(5, 2)]),
+        ]);
+
+        let registry = Registry::default();
+        client.register_metrics(&registry);
+
+        // when
+        let result = client.block_at_height(5).await;
+
+        // then
+        assert!(matches!(result, Err(Error::Inconsistent(_))));
+
+        let metrics = registry.gather();
+        let disagreements_metric = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "fuel_node_disagreements")
+            .and_then(|metric| metric.get_metric().first())
+            .map(Metric::get_counter)
+            .unwrap();
+
+        assert_eq!(disagreements_metric.get_value(), 1f64);
+    }
+
+    #[tokio::test]
+    async fn lagging_node_does_not_hold_back_the_block() {
+        // given
+        let block = given_a_block(5, 1);
+        let client = given_client(vec![
+            given_node_at(given_a_block(3, 1), vec![]),
+            given_node_at(block, vec![block]),
+        ]);
+
+        // when
+        let result = client.block_at_height(5).await.unwrap();
+
+        // then
+        assert_eq!(result, Some(block));
+    }
+
+    #[tokio::test]
+    async fn block_data_fails_over_to_reachable_node() {
+        // given
+        let mut reachable = MockApi::new();
+        reachable
+            .expect_block_data()
+            .with(eq(5))
+            .returning(|_| Ok(Some(vec![1, 2, 3])));
+
+        let client = given_client(vec![given_unreachable_node(), reachable]);
+
+        // when
+        let data = client.block_data(5).await.unwrap();
+
+        // then
+        assert_eq!(data, Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn follows_the_first_node_able_to_push_blocks() {
+        // given
+        let block = given_a_block(5, 1);
+        let mut unable = MockApi::new();
+        unable.expect_subscribe_to_blocks().returning(|| Ok(None));
+        let mut able = MockApi::new();
+        able.expect_subscribe_to_blocks()
+            .returning(move || Ok(Some(Box::pin(futures::stream::iter([Ok(block)])))));
+
+        let client = given_client(vec![given_unreachable_node(), unable, able]);
+
+        // when
+        let blocks = client.subscribe_to_blocks().await.unwrap().unwrap();
+
+        // then
+        let blocks: Vec<_> = blocks.collect().await;
+        assert!(matches!(blocks.as_slice(), [Ok(pushed)] if *pushed == block));
+    }
+
+    #[tokio::test]
+    async fn errors_if_no_node_is_reachable() {
+        // given
+        let client = given_client(vec![given_unreachable_node(), given_unreachable_node()]);
+
+        // when
+        let result = client.latest_block().await;
+
+        // then
+        assert!(matches!(result, Err(Error::Network(_))));
+    }
+
+    fn given_client(nodes: Vec<MockApi>) -> MultiNodeClient<MockApi> {
+        let nodes = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| (format!("node{i}"), node, ConnectionHealthTracker::new(1)))
+            .collect();
+
+        MultiNodeClient::from_nodes(nodes, ClientMetrics::default())
+    }
+
+    fn given_node_at(latest_block: FuelBlock, blocks: Vec<FuelBlock>) -> MockApi {
+        let mut node = MockApi::new();
+        node.expect_latest_block()
+            .returning(move || Ok(latest_block));
+        node.expect_block_at_height().returning(move |height| {
+            Ok(blocks.iter().find(|block| block.height == height).copied())
+        });
+
+        node
+    }
+
+    fn given_unreachable_node() -> MockApi {
+        let mut node = MockApi::new();
+        node.expect_latest_block()
+            .returning(|| Err(Error::Network("unreachable".to_string())));
+        node.expect_block_at_height()
+            .returning(|_| Err(Error::Network("unreachable".to_string())));
+        node.expect_block_data()
+            .returning(|_| Err(Error::Network("unreachable".to_string())));
+        node.expect_subscribe_to_blocks()
+            .returning(|| Err(Error::Network("unreachable".to_string())));
+
+        node
+    }
+
+    fn given_a_block(height: u32, hash: u8) -> FuelBlock {
+        FuelBlock {
+            hash: [hash; 32],
+            height,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 #![deny(unused_crate_dependencies)]
 mod connection_health_tracker;
+mod endpoint_name;
 pub use connection_health_tracker::*;
+pub use endpoint_name::endpoint_name;
 
 pub type HealthChecker = Box<dyn HealthCheck>;
 pub trait HealthCheck: Send + Sync {
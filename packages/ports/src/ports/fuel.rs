@@ -1,16 +1,55 @@
-use crate::types::FuelBlock;
+use std::pin::Pin;
+
+use crate::types::{FuelBlock, FuelBlockHeader, Stream};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("{0}")]
     Network(String),
+    #[error("request timed out: {0}")]
+    Timeout(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("invalid data: {0}")]
+    InvalidData(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Fuel nodes disagree: {0}")]
+    Inconsistent(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub type BlockStream = Pin<Box<dyn Stream<Item = Result<FuelBlock>> + Send>>;
+
+impl Error {
+    /// Whether the request may succeed when tried again later, as opposed to failing until
+    /// someone steps in.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(_) | Self::Timeout(_) => true,
+            Self::InvalidData(_) | Self::Inconsistent(_) => false,
+        }
+    }
+
+    /// Names the kind of error, e.g. to label metrics with.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Network(_) => "network",
+            Self::Timeout(_) => "timeout",
+            Self::InvalidData(_) => "invalid_data",
+            Self::Inconsistent(_) => "inconsistent",
+        }
+    }
+}
+
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
 #[async_trait::async_trait]
 pub trait Api: Send + Sync {
     async fn block_at_height(&self, height: u32) -> Result<Option<FuelBlock>>;
     async fn latest_block(&self) -> Result<FuelBlock>;
+    async fn block_header_at_height(&self, height: u32) -> Result<Option<FuelBlockHeader>>;
+    /// The header and transactions of the block at `height`, encoded to be posted on L1.
+    async fn block_data(&self, height: u32) -> Result<Option<Vec<u8>>>;
+    /// New blocks as they get produced, `None` if the node can't push them. The stream ends once
+    /// the subscription drops.
+    async fn subscribe_to_blocks(&self) -> Result<Option<BlockStream>>;
 }
@@ -1,28 +1,121 @@
 use std::pin::Pin;
 
-use crate::types::{FuelBlock, FuelBlockCommittedOnL1, InvalidL1Height, L1Height, Stream, U256};
+use crate::types::{
+    FuelBlock, FuelBlockCommittedOnL1, InvalidL1Height, L1Height, L1Tx, Stream, TxState, U256,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("network error: {0}")]
     Network(String),
+    #[error("request timed out: {0}")]
+    Timeout(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("rate limited: {0}")]
+    RateLimited(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("invalid data: {0}")]
+    InvalidData(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("state contract is paused")]
+    ContractPaused,
+    #[error("committer is not authorized: {0}")]
+    Unauthorized(String),
+    #[error("commit height rejected: {0}")]
+    InvalidCommitHeight(String),
+    #[error("contract call reverted: {0}")]
+    Reverted(String),
+    #[error("rpc endpoints disagree: {0}")]
+    NoQuorum(String),
+    #[error("tx nonce conflicts with the ones already known: {0}")]
+    NonceConflict(String),
     #[error("{0}")]
     Other(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+impl Error {
+    /// Whether the request may succeed when tried again later, as opposed to failing until
+    /// someone steps in.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(_)
+            | Self::Timeout(_)
+            | Self::RateLimited(_)
+            | Self::ContractPaused
+            | Self::NoQuorum(_)
+            | Self::NonceConflict(_) => true,
+            Self::InvalidData(_)
+            | Self::Unauthorized(_)
+            | Self::InvalidCommitHeight(_)
+            | Self::Reverted(_)
+            | Self::Other(_) => false,
+        }
+    }
+
+    /// Names the kind of error, e.g. to label metrics with.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Network(_) => "network",
+            Self::Timeout(_) => "timeout",
+            Self::RateLimited(_) => "rate_limited",
+            Self::InvalidData(_) => "invalid_data",
+            Self::ContractPaused => "contract_paused",
+            Self::Unauthorized(_) => "unauthorized",
+            Self::InvalidCommitHeight(_) => "invalid_commit_height",
+            Self::Reverted(_) => "reverted",
+            Self::NoQuorum(_) => "no_quorum",
+            Self::NonceConflict(_) => "nonce_conflict",
+            Self::Other(_) => "other",
+        }
+    }
+}
+
 impl From<InvalidL1Height> for Error {
     fn from(err: InvalidL1Height) -> Self {
-        Self::Other(err.to_string())
+        Self::InvalidData(Box::new(err))
     }
 }
 
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
 #[async_trait::async_trait]
 pub trait Contract: Send + Sync {
-    async fn submit(&self, block: FuelBlock) -> Result<()>;
+    /// Sends the commitment of `block` as a tx with the given `nonce`.
+    async fn submit(&self, block: FuelBlock, nonce: u64) -> Result<L1Tx>;
+    /// Like `submit`, additionally carrying `data` in EIP-4844 blobs of the same tx.
+    async fn submit_with_blobs(&self, block: FuelBlock, data: Vec<u8>, nonce: u64) -> Result<L1Tx>;
+    /// Resends the commitment of `block` reusing `nonce`, so that it replaces any transaction
+    /// still pending with that nonce.
+    async fn resubmit(
+        &self,
+        block: FuelBlock,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> Result<L1Tx>;
+    /// Like `resubmit`, for a transaction that carried `data` in EIP-4844 blobs. Nodes only accept
+    /// a blob transaction as the replacement of another.
+    async fn resubmit_with_blobs(
+        &self,
+        block: FuelBlock,
+        data: Vec<u8>,
+        nonce: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> Result<L1Tx>;
     fn event_streamer(&self, height: L1Height) -> Box<dyn EventStreamer + Send + Sync>;
+    /// The fuel block hash the contract holds for `commit_height`, all zeroes if nothing was
+    /// committed at it.
+    async fn block_hash_at_commit_height(&self, commit_height: u32) -> Result<[u8; 32]>;
+    /// The commit event of `block` emitted on the canonical chain at or after `from_height`,
+    /// `None` if there is none.
+    async fn commit_event(
+        &self,
+        block: FuelBlock,
+        from_height: L1Height,
+    ) -> Result<Option<FuelBlockCommittedOnL1>>;
+    async fn finalized(&self, block: FuelBlock) -> Result<bool>;
 }
 
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
@@ -30,6 +123,18 @@ pub trait Contract: Send + Sync {
 pub trait Api {
     async fn get_block_number(&self) -> Result<L1Height>;
     async fn balance(&self) -> Result<U256>;
+    /// The nonce the next tx of the committer's account should have, counting txs still pending.
+    async fn pending_nonce(&self) -> Result<u64>;
+    async fn tx_state(&self, tx_hash: [u8; 32]) -> Result<TxState>;
+    /// Hash of the canonical block at `height`, `None` if the chain isn't that long.
+    async fn block_hash_at(&self, height: L1Height) -> Result<Option<[u8; 32]>>;
+}
+
+#[cfg_attr(feature = "test-helpers", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContractState: Send + Sync {
+    /// Whether the state contract is paused as of the latest L1 block, rejecting any commit.
+    async fn paused(&self) -> Result<bool>;
 }
 
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::types::BlockSubmission;
+use crate::types::{
+    BlockSubmission, CommitVerification, ForeignCommit, FuelBlockHeader, L1Block, L1Height,
+    SubmissionTx, TxState,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -12,11 +15,84 @@ pub enum Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+impl Error {
+    /// Whether the query may succeed when run again later, data that can't be converted won't.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Database(_))
+    }
+
+    /// Names the kind of error, e.g. to label metrics with.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Database(_) => "database",
+            Self::Conversion(_) => "conversion",
+        }
+    }
+}
+
 #[async_trait::async_trait]
 #[impl_tools::autoimpl(for<T: trait> &T, &mut T, Arc<T>, Box<T>)]
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
 pub trait Storage: Send + Sync {
     async fn insert(&self, submission: BlockSubmission) -> Result<()>;
     async fn submission_w_latest_block(&self) -> Result<Option<BlockSubmission>>;
-    async fn set_submission_completed(&self, fuel_block_hash: [u8; 32]) -> Result<BlockSubmission>;
+    /// Up to `limit` submissions with the highest fuel block heights, highest first.
+    async fn latest_submissions(&self, limit: u32) -> Result<Vec<BlockSubmission>>;
+    async fn submission(&self, fuel_block_hash: [u8; 32]) -> Result<Option<BlockSubmission>>;
+    async fn set_submission_completed(
+        &self,
+        fuel_block_hash: [u8; 32],
+        committed_in: L1Block,
+    ) -> Result<BlockSubmission>;
+    /// Completed submissions whose commit event isn't yet buried under enough L1 blocks.
+    async fn unconfirmed_submissions(&self) -> Result<Vec<BlockSubmission>>;
+    async fn set_submission_confirmed(&self, fuel_block_hash: [u8; 32]) -> Result<()>;
+    /// Marks a completed submission as incomplete again, recording a new commit attempt. Its mined
+    /// txs are marked as dropped.
+    async fn revert_submission_completion(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
+    ) -> Result<BlockSubmission>;
+    /// The incomplete submission with the lowest `submittal_height`.
+    async fn oldest_incomplete_submission(&self) -> Result<Option<BlockSubmission>>;
+    /// Incomplete submissions sent at or before `submitted_before` that have neither a pending nor a
+    /// mined tx.
+    async fn stale_submissions(&self, submitted_before: L1Height) -> Result<Vec<BlockSubmission>>;
+    async fn record_submission_attempt(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
+    ) -> Result<BlockSubmission>;
+    async fn insert_tx(&self, tx: SubmissionTx) -> Result<()>;
+    async fn pending_txs(&self) -> Result<Vec<SubmissionTx>>;
+    /// Whether a tx committing the given block is pending or already mined.
+    async fn has_live_tx(&self, fuel_block_hash: [u8; 32]) -> Result<bool>;
+    /// The pending txs along with the txs they replaced, any of which may still be mined instead,
+    /// ordered by nonce.
+    async fn unsettled_txs(&self) -> Result<Vec<SubmissionTx>>;
+    async fn update_tx_state(&self, tx_hash: [u8; 32], state: TxState) -> Result<()>;
+    async fn record_replacement(&self, replaced_tx_hash: [u8; 32], tx: SubmissionTx) -> Result<()>;
+    /// The L1 height of the last commit event handled by the commit listener.
+    async fn l1_event_cursor(&self) -> Result<Option<L1Height>>;
+    /// Moves the cursor forward to `height`, a lower height leaves it untouched.
+    async fn advance_l1_event_cursor(&self, height: L1Height) -> Result<()>;
+    /// The nonce of the last commit tx sent, as handed out by the nonce manager.
+    async fn last_used_nonce(&self) -> Result<Option<u64>>;
+    async fn set_last_used_nonce(&self, nonce: u64) -> Result<()>;
+    /// Records a commit of a block not submitted by us, replacing any earlier record of it.
+    async fn insert_foreign_commit(&self, commit: ForeignCommit) -> Result<()>;
+    async fn foreign_commit(&self, fuel_block_hash: [u8; 32]) -> Result<Option<ForeignCommit>>;
+    /// Stores the header of a fuel block, a header already stored for its hash is kept.
+    async fn insert_fuel_block_header(&self, header: FuelBlockHeader) -> Result<()>;
+    async fn fuel_block_header(&self, fuel_block_hash: [u8; 32])
+        -> Result<Option<FuelBlockHeader>>;
+    /// Stores what the state contract holds for a submission, replacing any earlier verification.
+    async fn set_commit_verification(&self, verification: CommitVerification) -> Result<()>;
+    async fn commit_verification(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> Result<Option<CommitVerification>>;
 }
@@ -1,16 +1,26 @@
 #[cfg(feature = "l1")]
 pub use ethers_core::types::{H160, U256};
-#[cfg(feature = "l1")]
+#[cfg(any(feature = "l1", feature = "fuel"))]
 pub use futures::Stream;
 
 mod block_submission;
+mod commit_verification;
+mod foreign_commit;
 mod fuel_block;
 #[cfg(feature = "l1")]
 mod fuel_block_committed_on_l1;
+mod fuel_block_header;
+mod l1_block;
 mod l1_height;
+mod l1_tx;
 
 pub use block_submission::*;
+pub use commit_verification::*;
+pub use foreign_commit::*;
 pub use fuel_block::*;
 #[cfg(feature = "l1")]
 pub use fuel_block_committed_on_l1::*;
+pub use fuel_block_header::*;
+pub use l1_block::*;
 pub use l1_height::*;
+pub use l1_tx::*;
@@ -1,4 +1,4 @@
-use crate::types::{FuelBlock, L1Height};
+use crate::types::{FuelBlock, L1Block, L1Height};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BlockSubmission {
@@ -7,15 +7,25 @@ pub struct BlockSubmission {
     // L1 block height moments before submitting the fuel block. Used to filter stale events in
     // the commit listener.
     pub submittal_height: L1Height,
+    // How many times the block was sent to L1 for committing.
+    pub attempts: u32,
+    // The L1 block containing the commit event, set once the submission is completed.
+    pub committed_in: Option<L1Block>,
+    // Whether `committed_in` is buried deep enough to no longer be reorged out.
+    pub confirmed: bool,
 }
 
 #[cfg(feature = "test-helpers")]
 impl rand::distributions::Distribution<BlockSubmission> for rand::distributions::Standard {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> BlockSubmission {
+        let completed = rng.gen();
         BlockSubmission {
             block: rng.gen(),
-            completed: rng.gen(),
+            completed,
             submittal_height: rng.gen(),
+            attempts: rng.gen_range(1..=i32::MAX as u32),
+            committed_in: completed.then(|| rng.gen()),
+            confirmed: completed && rng.gen(),
         }
     }
 }
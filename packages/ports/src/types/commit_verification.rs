@@ -0,0 +1,27 @@
+/// What the state contract holds for a submitted fuel block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitVerification {
+    pub fuel_block_hash: [u8; 32],
+    // The hash stored by the contract at the commit height of the block, `None` if nothing was
+    // committed at it.
+    pub committed_hash: Option<[u8; 32]>,
+    pub finalized: bool,
+}
+
+impl CommitVerification {
+    #[must_use]
+    pub fn matches(&self) -> bool {
+        self.committed_hash == Some(self.fuel_block_hash)
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<CommitVerification> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> CommitVerification {
+        CommitVerification {
+            fuel_block_hash: rng.gen(),
+            committed_hash: rng.gen(),
+            finalized: rng.gen(),
+        }
+    }
+}
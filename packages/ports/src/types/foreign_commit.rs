@@ -0,0 +1,25 @@
+use crate::types::{FuelBlock, L1Block};
+
+/// A commit event for a fuel block this committer never submitted, e.g. one made by another
+/// committer instance or by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignCommit {
+    // The height is derived from the commit height of the event.
+    pub block: FuelBlock,
+    // The L1 block that emitted the commit event.
+    pub included_in: L1Block,
+    // Whether the fuel node has the same block at that height, `None` if it doesn't know about
+    // the height.
+    pub matches_fuel_chain: Option<bool>,
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<ForeignCommit> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ForeignCommit {
+        ForeignCommit {
+            block: rng.gen(),
+            included_in: rng.gen(),
+            matches_fuel_chain: rng.gen(),
+        }
+    }
+}
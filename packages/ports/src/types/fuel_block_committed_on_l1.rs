@@ -1,9 +1,11 @@
-use crate::types::U256;
+use crate::types::{L1Block, U256};
 
 #[derive(Clone, Copy)]
 pub struct FuelBlockCommittedOnL1 {
     pub fuel_block_hash: [u8; 32],
     pub commit_height: U256,
+    // The L1 block that emitted the commit event.
+    pub included_in: L1Block,
 }
 
 impl std::fmt::Debug for FuelBlockCommittedOnL1 {
@@ -15,6 +17,7 @@ impl std::fmt::Debug for FuelBlockCommittedOnL1 {
         f.debug_struct("FuelBlockCommittedOnL1")
             .field("hash", &hash)
             .field("commit_height", &self.commit_height)
+            .field("included_in", &self.included_in)
             .finish()
     }
 }
@@ -0,0 +1,42 @@
+use crate::types::FuelBlock;
+
+/// The header fields of a fuel block kept around so that it can be verified and audited without
+/// asking the fuel node again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuelBlockHeader {
+    pub block: FuelBlock,
+    pub prev_root: [u8; 32],
+    // TAI64 timestamp
+    pub time: u64,
+    pub da_height: u64,
+    pub transactions_root: [u8; 32],
+    pub message_outbox_root: [u8; 32],
+    // Commits to the header fields not listed here.
+    pub application_hash: [u8; 32],
+    // Public key recovered from the PoA signature, `None` for the genesis block.
+    pub producer: Option<[u8; 64]>,
+    pub transactions_count: u16,
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<FuelBlockHeader> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> FuelBlockHeader {
+        let producer = rng.gen::<bool>().then(|| {
+            let mut producer = [0; 64];
+            rng.fill(&mut producer[..]);
+            producer
+        });
+
+        FuelBlockHeader {
+            block: rng.gen(),
+            prev_root: rng.gen(),
+            time: rng.gen_range(0..=i64::MAX as u64),
+            da_height: rng.gen_range(0..=i64::MAX as u64),
+            transactions_root: rng.gen(),
+            message_outbox_root: rng.gen(),
+            application_hash: rng.gen(),
+            producer,
+            transactions_count: rng.gen(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::types::L1Height;
+
+/// Identifies an L1 block, used to notice when it gets reorged out of the canonical chain.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct L1Block {
+    pub height: L1Height,
+    pub hash: [u8; 32],
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<L1Block> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> L1Block {
+        L1Block {
+            height: rng.gen(),
+            hash: rng.gen(),
+        }
+    }
+}
+
+impl std::fmt::Debug for L1Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hash = self.hash.map(|byte| format!("{byte:02x?}")).join("");
+        f.debug_struct("L1Block")
+            .field("height", &self.height)
+            .field("hash", &hash)
+            .finish()
+    }
+}
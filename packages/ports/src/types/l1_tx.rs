@@ -0,0 +1,89 @@
+use crate::types::L1Height;
+
+/// An L1 transaction carrying a fuel block commitment, as handed to the mempool.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct L1Tx {
+    pub hash: [u8; 32],
+    pub nonce: u64,
+    pub max_fee_per_gas: u64,
+    pub max_priority_fee_per_gas: u64,
+    /// Set for EIP-4844 transactions carrying blobs.
+    pub max_fee_per_blob_gas: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxState {
+    /// Broadcasted but not yet included in a block.
+    Pending,
+    /// Included in a block and executed successfully.
+    Mined,
+    /// Included in a block but the execution reverted.
+    Reverted,
+    /// Neither included in a block nor known to the mempool anymore.
+    Dropped,
+    /// Superseded by a transaction with the same nonce but higher fees.
+    Replaced,
+}
+
+/// Tracks the L1 transaction sent for a given block submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmissionTx {
+    pub fuel_block_hash: [u8; 32],
+    pub tx: L1Tx,
+    pub state: TxState,
+    // L1 block height moments before the transaction was sent.
+    pub submittal_height: L1Height,
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<L1Tx> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> L1Tx {
+        L1Tx {
+            hash: rng.gen(),
+            nonce: rng.gen_range(0..=i64::MAX as u64),
+            max_fee_per_gas: rng.gen_range(0..=i64::MAX as u64),
+            max_priority_fee_per_gas: rng.gen_range(0..=i64::MAX as u64),
+            max_fee_per_blob_gas: rng
+                .gen::<bool>()
+                .then(|| rng.gen_range(0..=i64::MAX as u64)),
+        }
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<TxState> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TxState {
+        match rng.gen_range(0..5) {
+            0 => TxState::Pending,
+            1 => TxState::Mined,
+            2 => TxState::Reverted,
+            3 => TxState::Dropped,
+            _ => TxState::Replaced,
+        }
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl rand::distributions::Distribution<SubmissionTx> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SubmissionTx {
+        SubmissionTx {
+            fuel_block_hash: rng.gen(),
+            tx: rng.gen(),
+            state: rng.gen(),
+            submittal_height: rng.gen(),
+        }
+    }
+}
+
+impl std::fmt::Debug for L1Tx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hash = self.hash.map(|byte| format!("{byte:02x?}")).join("");
+        f.debug_struct("L1Tx")
+            .field("hash", &hash)
+            .field("nonce", &self.nonce)
+            .field("max_fee_per_gas", &self.max_fee_per_gas)
+            .field("max_priority_fee_per_gas", &self.max_priority_fee_per_gas)
+            .field("max_fee_per_blob_gas", &self.max_fee_per_blob_gas)
+            .finish()
+    }
+}
@@ -1,31 +1,102 @@
+use std::collections::VecDeque;
+
 use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntCounterVec, Opts},
+    RegistersMetrics,
+};
 use ports::{
     storage::Storage,
-    types::{BlockSubmission, FuelBlock},
+    types::{BlockSubmission, FuelBlock, SubmissionTx, TxState},
 };
 use tokio::sync::mpsc::Receiver;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use super::Runner;
-use crate::Result;
+use crate::{nonce_manager::NonceManager, ContractPause, Error, Result};
+
+struct Metrics {
+    block_commit_errors: IntCounterVec,
+}
+
+impl<C, Db> RegistersMetrics for BlockCommitter<C, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.metrics.block_commit_errors.clone())]
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let block_commit_errors = IntCounterVec::new(
+            Opts::new(
+                "block_commit_errors",
+                "Number of fuel blocks that failed to be committed, by kind of error.",
+            ),
+            &["kind"],
+        )
+        .expect("block_commit_errors metric to be correctly configured");
+
+        Self {
+            block_commit_errors,
+        }
+    }
+}
 
 pub struct BlockCommitter<C, Db> {
     rx_block: Receiver<FuelBlock>,
     l1: C,
     storage: Db,
+    pause: ContractPause,
+    // blocks held back while the state contract is paused
+    queue: VecDeque<FuelBlock>,
+    nonce_manager: NonceManager,
+    // set when the block data is to be posted in blobs along with the commit
+    blob_source: Option<Box<dyn ports::fuel::Api>>,
+    metrics: Metrics,
 }
 
 impl<L1, Db> BlockCommitter<L1, Db> {
-    pub fn new(rx_block: Receiver<FuelBlock>, l1: L1, storage: Db) -> Self {
+    pub fn new(rx_block: Receiver<FuelBlock>, l1: L1, storage: Db, pause: ContractPause) -> Self {
         Self {
             rx_block,
             l1,
             storage,
+            pause,
+            queue: VecDeque::new(),
+            nonce_manager: NonceManager::default(),
+            blob_source: None,
+            metrics: Metrics::default(),
         }
     }
 
+    /// Posts the data of every committed block, as fetched from `fuel_adapter`, in EIP-4844 blobs
+    /// of the commit tx.
+    #[must_use]
+    pub fn with_blobs(mut self, fuel_adapter: impl ports::fuel::Api + 'static) -> Self {
+        self.blob_source = Some(Box::new(fuel_adapter));
+        self
+    }
+
+    /// Waits for a block to commit, holding received blocks back for as long as the state
+    /// contract is paused.
     async fn next_fuel_block_for_committal(&mut self) -> Option<FuelBlock> {
-        self.rx_block.recv().await
+        loop {
+            if !self.pause.paused() {
+                if let Some(block) = self.queue.pop_front() {
+                    return Some(block);
+                }
+            }
+
+            tokio::select! {
+                block = self.rx_block.recv() => {
+                    let block = block?;
+                    if !self.queue.contains(&block) {
+                        self.queue.push_back(block);
+                    }
+                }
+                () = self.pause.unpaused(), if !self.queue.is_empty() => {}
+            }
+        }
     }
 }
 
@@ -34,19 +105,85 @@ where
     A: ports::l1::Contract + ports::l1::Api,
     Db: Storage,
 {
-    async fn submit_block(&self, fuel_block: FuelBlock) -> Result<()> {
+    async fn submit_block(&mut self, fuel_block: FuelBlock) -> Result<()> {
         let submittal_height = self.l1.get_block_number().await?;
 
-        let submission = BlockSubmission {
-            block: fuel_block,
-            submittal_height,
-            completed: false,
-        };
+        match self.storage.submission(fuel_block.hash).await? {
+            Some(submission) if submission.completed => {
+                info!("{fuel_block:?} already committed, skipping");
+                return Ok(());
+            }
+            // the block watcher may enqueue a block again while its commit tx is still pending or
+            // mined but not yet seen by the commit listener
+            Some(_) if self.storage.has_live_tx(fuel_block.hash).await? => {
+                info!("{fuel_block:?} already has a live commit tx, skipping");
+                return Ok(());
+            }
+            // either the attempt was recorded by whoever re-enqueued the block, or sending the
+            // previous tx failed before it could be recorded
+            Some(_) => {}
+            None => {
+                let submission = BlockSubmission {
+                    block: fuel_block,
+                    submittal_height,
+                    completed: false,
+                    attempts: 1,
+                    committed_in: None,
+                    confirmed: false,
+                };
 
-        self.storage.insert(submission).await?;
+                self.storage.insert(submission).await?;
+            }
+        }
 
-        // if we have a network failure the DB entry will be left at completed:false.
-        self.l1.submit(fuel_block).await?;
+        // if we have a network failure the DB entry will be left at completed:false. The
+        // `SubmissionRetrier` will re-enqueue it once it becomes stale.
+        let nonce = self.nonce_manager.next(&self.l1, &self.storage).await?;
+        let response = match &self.blob_source {
+            Some(fuel_adapter) => {
+                let data = fuel_adapter
+                    .block_data(fuel_block.height)
+                    .await?
+                    .ok_or_else(|| {
+                        Error::MissingFuelBlock(format!(
+                            "{fuel_block:?} not found on the fuel chain"
+                        ))
+                    })?;
+                self.l1.submit_with_blobs(fuel_block, data, nonce).await
+            }
+            None => self.l1.submit(fuel_block, nonce).await,
+        };
+        let tx = match response {
+            // paused since the pause tracker last looked, hold the block back until unpaused
+            Err(ports::l1::Error::ContractPaused) => {
+                warn!("state contract is paused, holding back {fuel_block:?}");
+                self.pause.set_paused(true);
+                self.queue.push_back(fuel_block);
+                return Ok(());
+            }
+            Err(ports::l1::Error::Unauthorized(reason)) => {
+                return Err(ports::l1::Error::Unauthorized(format!(
+                    "check the role of the committer on the state contract: {reason}"
+                ))
+                .into());
+            }
+            Err(err) => {
+                // the tx might have been sent nonetheless, only the chain can tell
+                self.nonce_manager.reset();
+                return Err(err.into());
+            }
+            Ok(tx) => tx,
+        };
+        self.nonce_manager.used(tx.nonce, &self.storage).await?;
+
+        self.storage
+            .insert_tx(SubmissionTx {
+                fuel_block_hash: fuel_block.hash,
+                tx,
+                state: TxState::Pending,
+                submittal_height,
+            })
+            .await?;
 
         Ok(())
     }
@@ -61,7 +198,16 @@ where
     async fn run(&mut self) -> Result<()> {
         while let Some(fuel_block) = self.next_fuel_block_for_committal().await {
             if let Err(error) = self.submit_block(fuel_block).await {
-                error!("{error}");
+                self.metrics
+                    .block_commit_errors
+                    .with_label_values(&[error.kind()])
+                    .inc();
+
+                if error.is_retryable() {
+                    warn!("failed to commit {fuel_block:?}, it will be retried: {error}");
+                } else {
+                    error!("failed to commit {fuel_block:?}: {error}");
+                }
             } else {
                 info!("submitted {fuel_block:?}!");
             }
@@ -77,40 +223,94 @@ where
 mod tests {
     use std::time::Duration;
 
+    use metrics::prometheus::{proto::Metric, Registry};
     use mockall::predicate;
-    use ports::{
-        l1::{Contract, EventStreamer, MockApi, MockContract},
-        types::{L1Height, U256},
-    };
+    use ports::types::{L1Block, L1Tx};
     use rand::Rng;
     use storage::PostgresProcess;
 
     use super::*;
+    use crate::{test_utils::MockL1, FinalityTracker};
 
-    struct MockL1 {
-        api: MockApi,
-        contract: MockContract,
+    #[tokio::test]
+    async fn block_committer_holds_blocks_while_contract_is_paused() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1.contract.expect_submit().never();
+        tx.try_send(block).unwrap();
+
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), pause).await;
+
+        // then
+        assert!(db.submission(block.hash).await.unwrap().is_none());
     }
 
-    #[async_trait::async_trait]
-    impl Contract for MockL1 {
-        async fn submit(&self, block: FuelBlock) -> ports::l1::Result<()> {
-            self.contract.submit(block).await
-        }
-        fn event_streamer(&self, height: L1Height) -> Box<dyn EventStreamer + Send + Sync> {
-            self.contract.event_streamer(height)
-        }
+    #[tokio::test]
+    async fn block_committer_holds_block_rejected_by_paused_contract() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .contract
+            .expect_submit()
+            .times(1)
+            .returning(|_, _| Err(ports::l1::Error::ContractPaused));
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.api.expect_pending_nonce().returning(|| Ok(0));
+        tx.try_send(block).unwrap();
+
+        let pause = ContractPause::default();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), pause.clone()).await;
+
+        // then
+        assert!(pause.paused());
+        assert!(db.pending_txs().await.unwrap().is_empty());
     }
 
-    #[cfg_attr(feature = "test-helpers", mockall::automock)]
-    #[async_trait::async_trait]
-    impl ports::l1::Api for MockL1 {
-        async fn get_block_number(&self) -> ports::l1::Result<L1Height> {
-            self.api.get_block_number().await
-        }
-        async fn balance(&self) -> ports::l1::Result<U256> {
-            self.api.balance().await
-        }
+    #[tokio::test]
+    async fn block_committer_submits_held_blocks_once_contract_is_unpaused() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mock_l1 = given_l1_that_expects_submission(block);
+        tx.try_send(block).unwrap();
+
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+        tokio::spawn({
+            let pause = pause.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                pause.set_paused(false);
+            }
+        });
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), pause).await;
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap().len(), 1);
     }
 
     #[tokio::test]
@@ -126,37 +326,397 @@ mod tests {
         tx.try_send(block).unwrap();
 
         // when
-        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone()).await;
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
 
         // then
         let last_submission = db.submission_w_latest_block().await.unwrap().unwrap();
         assert_eq!(expected_height, last_submission.block.height);
     }
 
+    #[tokio::test]
+    async fn block_committer_sends_consecutive_nonces() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let blocks: [FuelBlock; 2] = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1
+            .api
+            .expect_pending_nonce()
+            .times(1)
+            .returning(|| Ok(3));
+        for (block, nonce) in blocks.into_iter().zip(3..) {
+            mock_l1
+                .contract
+                .expect_submit()
+                .with(predicate::eq(block), predicate::eq(nonce))
+                .times(1)
+                .returning(move |_, nonce| {
+                    Ok(L1Tx {
+                        hash: rand::thread_rng().gen(),
+                        nonce,
+                        ..given_l1_tx()
+                    })
+                });
+            tx.try_send(block).unwrap();
+        }
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap().len(), 2);
+        assert_eq!(db.last_used_nonce().await.unwrap(), Some(4));
+    }
+
+    #[tokio::test]
+    async fn block_committer_will_track_the_submitted_tx() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mock_l1 = given_l1_that_expects_submission(block);
+        tx.try_send(block).unwrap();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        let pending_txs = db.pending_txs().await.unwrap();
+        assert_eq!(
+            pending_txs,
+            vec![SubmissionTx {
+                fuel_block_hash: block.hash,
+                tx: given_l1_tx(),
+                state: TxState::Pending,
+                submittal_height: 0u32.into(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_committer_posts_block_data_in_blobs() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut fuel_adapter = ports::fuel::MockApi::new();
+        fuel_adapter
+            .expect_block_data()
+            .with(predicate::eq(block.height))
+            .returning(|_| Ok(Some(vec![1, 2, 3])));
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1.contract.expect_submit().never();
+        mock_l1
+            .contract
+            .expect_submit_with_blobs()
+            .with(
+                predicate::eq(block),
+                predicate::eq(vec![1, 2, 3]),
+                predicate::eq(0),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(given_l1_tx()));
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.api.expect_pending_nonce().returning(|| Ok(0));
+        tx.try_send(block).unwrap();
+
+        let mut block_committer =
+            BlockCommitter::new(rx, mock_l1, db.clone(), ContractPause::default())
+                .with_blobs(fuel_adapter);
+
+        // when
+        let _ = tokio::time::timeout(Duration::from_millis(250), block_committer.run()).await;
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn block_committer_counts_failed_commits_by_kind_of_error() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .contract
+            .expect_submit()
+            .times(1)
+            .returning(|_, _| Err(ports::l1::Error::Reverted("reason".to_string())));
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.api.expect_pending_nonce().returning(|| Ok(0));
+        tx.try_send(block).unwrap();
+
+        let mut block_committer =
+            BlockCommitter::new(rx, mock_l1, db.clone(), ContractPause::default());
+        let registry = Registry::default();
+        block_committer.register_metrics(&registry);
+
+        // when
+        let _ = tokio::time::timeout(Duration::from_millis(250), block_committer.run()).await;
+
+        // then
+        let metrics = registry.gather();
+        let reverted_commits = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "block_commit_errors")
+            .and_then(|metric| metric.get_metric().first())
+            .filter(|metric| metric.get_label()[0].get_value() == "reverted")
+            .map(Metric::get_counter)
+            .unwrap();
+
+        assert_eq!(reverted_commits.get_value(), 1f64);
+    }
+
     fn given_l1_that_expects_submission(block: FuelBlock) -> MockL1 {
-        let mut l1 = MockL1 {
-            api: MockApi::new(),
-            contract: MockContract::new(),
-        };
+        let mut l1 = MockL1::new();
         l1.contract
             .expect_submit()
-            .with(predicate::eq(block))
-            .return_once(move |_| Ok(()));
+            .with(predicate::eq(block), predicate::eq(0))
+            .return_once(move |_, _| Ok(given_l1_tx()));
 
         l1.api
             .expect_get_block_number()
             .return_once(move || Ok(0u32.into()));
+        l1.api.expect_pending_nonce().return_once(|| Ok(0));
 
         l1
     }
 
+    #[tokio::test]
+    async fn block_committer_will_resubmit_incomplete_submission() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            completed: false,
+            attempts: 2,
+            committed_in: None,
+            confirmed: false,
+            ..rand::thread_rng().gen()
+        };
+        db.insert(submission.clone()).await.unwrap();
+
+        let mock_l1 = given_l1_that_expects_submission(submission.block);
+        tx.try_send(submission.block).unwrap();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        assert_eq!(
+            db.submission(submission.block.hash).await.unwrap(),
+            Some(submission)
+        );
+        assert_eq!(db.pending_txs().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn block_committer_will_not_resubmit_completed_submission() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            completed: true,
+            ..rand::thread_rng().gen()
+        };
+        db.insert(submission.clone()).await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.contract.expect_submit().never();
+        tx.try_send(submission.block).unwrap();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        assert!(db.pending_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn block_committer_will_not_submit_block_enqueued_twice() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let block: FuelBlock = rand::thread_rng().gen();
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .contract
+            .expect_submit()
+            .with(predicate::eq(block), predicate::always())
+            .times(1)
+            .returning(move |_, _| Ok(given_l1_tx()));
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.api.expect_pending_nonce().returning(|| Ok(0));
+        tx.try_send(block).unwrap();
+        tx.try_send(block).unwrap();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn block_committer_will_not_resubmit_block_whose_tx_was_mined() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            completed: false,
+            committed_in: None,
+            confirmed: false,
+            ..rand::thread_rng().gen()
+        };
+        db.insert(submission.clone()).await.unwrap();
+        db.insert_tx(SubmissionTx {
+            fuel_block_hash: submission.block.hash,
+            tx: given_l1_tx(),
+            state: TxState::Mined,
+            submittal_height: 0u32.into(),
+        })
+        .await
+        .unwrap();
+
+        let mut mock_l1 = MockL1::new();
+        mock_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(0u32.into()));
+        mock_l1.contract.expect_submit().never();
+        tx.try_send(submission.block).unwrap();
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        assert!(db.pending_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn block_committer_commits_block_again_once_its_mined_tx_got_reorged_out() {
+        // given
+        let (tx, rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            completed: false,
+            committed_in: None,
+            confirmed: false,
+            ..rand::thread_rng().gen()
+        };
+        db.insert(submission.clone()).await.unwrap();
+        db.insert_tx(SubmissionTx {
+            fuel_block_hash: submission.block.hash,
+            tx: L1Tx {
+                hash: [2; 32],
+                ..given_l1_tx()
+            },
+            state: TxState::Mined,
+            submittal_height: 0u32.into(),
+        })
+        .await
+        .unwrap();
+        let committed_in = L1Block {
+            height: 10u32.into(),
+            hash: [1; 32],
+        };
+        db.set_submission_completed(submission.block.hash, committed_in)
+            .await
+            .unwrap();
+
+        let mut reorged_l1 = MockL1::new();
+        reorged_l1
+            .api
+            .expect_get_block_number()
+            .returning(|| Ok(15u32.into()));
+        reorged_l1
+            .api
+            .expect_block_hash_at()
+            .returning(|_| Ok(Some([0; 32])));
+        reorged_l1
+            .contract
+            .expect_commit_event()
+            .returning(|_, _| Ok(None));
+        FinalityTracker::new(reorged_l1, db.clone(), tx, 5)
+            .run()
+            .await
+            .unwrap();
+
+        let mock_l1 = given_l1_that_expects_submission(submission.block);
+
+        // when
+        spawn_committer_and_run_until_timeout(rx, mock_l1, db.clone(), ContractPause::default())
+            .await;
+
+        // then
+        let pending_txs = db.pending_txs().await.unwrap();
+        assert_eq!(pending_txs.len(), 1);
+        assert_eq!(pending_txs[0].tx, given_l1_tx());
+    }
+
+    fn given_l1_tx() -> L1Tx {
+        L1Tx {
+            hash: [1; 32],
+            nonce: 0,
+            max_fee_per_gas: 2,
+            max_priority_fee_per_gas: 1,
+            max_fee_per_blob_gas: None,
+        }
+    }
+
     async fn spawn_committer_and_run_until_timeout<Db: Storage>(
         rx: Receiver<FuelBlock>,
         mock_l1: MockL1,
         storage: Db,
+        pause: ContractPause,
     ) {
         let _ = tokio::time::timeout(Duration::from_millis(250), async move {
-            let mut block_committer = BlockCommitter::new(rx, mock_l1, storage);
+            let mut block_committer = BlockCommitter::new(rx, mock_l1, storage, pause);
             block_committer
                 .run()
                 .await
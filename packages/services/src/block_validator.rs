@@ -0,0 +1,184 @@
+use ports::types::FuelBlockHeader;
+use sha2::{Digest, Sha256};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub(crate) enum BlockValidationError {
+    #[error("block id 0x{reported} doesn't match the id 0x{computed} computed from its header")]
+    IdMismatch { reported: String, computed: String },
+    #[error("block isn't signed by a block producer")]
+    Unsigned,
+    #[error("block was produced by 0x{0}, which isn't an authorized block producer")]
+    UnauthorizedProducer(String),
+}
+
+/// Checks that a fuel block is the one an authorized producer signed, so that a misbehaving fuel
+/// node can't get arbitrary blocks committed.
+#[derive(Debug, Clone)]
+pub struct BlockValidator {
+    // `None` while the authorized producers aren't configured yet
+    authorized_producers: Option<Vec<[u8; 64]>>,
+}
+
+impl BlockValidator {
+    #[must_use]
+    pub fn new(authorized_producers: Vec<[u8; 64]>) -> Self {
+        Self {
+            authorized_producers: Some(authorized_producers),
+        }
+    }
+
+    /// Only checks that blocks match their headers, whoever produced them. Meant to bridge the
+    /// time until the authorized producers are configured.
+    #[must_use]
+    pub fn accepting_any_producer() -> Self {
+        Self {
+            authorized_producers: None,
+        }
+    }
+
+    pub(crate) fn validate(&self, header: &FuelBlockHeader) -> Result<(), BlockValidationError> {
+        let computed = block_id(header);
+        if computed != header.block.hash {
+            return Err(BlockValidationError::IdMismatch {
+                reported: hex::encode(header.block.hash),
+                computed: hex::encode(computed),
+            });
+        }
+
+        // The producer is recovered from a signature over the block id, a key of an authorized
+        // producer can only come out of it if that producer signed this very id.
+        let Some(authorized_producers) = &self.authorized_producers else {
+            return Ok(());
+        };
+        let producer = header.producer.ok_or(BlockValidationError::Unsigned)?;
+        if !authorized_producers.contains(&producer) {
+            return Err(BlockValidationError::UnauthorizedProducer(hex::encode(
+                producer,
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// The id fuel-core derives from the consensus header fields, the remaining fields being
+/// committed to by the application hash.
+pub(crate) fn block_id(header: &FuelBlockHeader) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(header.prev_root)
+        .chain_update(header.block.height.to_be_bytes())
+        .chain_update(header.time.to_be_bytes())
+        .chain_update(header.application_hash)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    const PRODUCER: [u8; 64] = [1; 64];
+
+    #[test]
+    fn accepts_block_signed_by_authorized_producer() {
+        // given
+        let header = given_a_header(Some(PRODUCER));
+        let validator = BlockValidator::new(vec![[2; 64], PRODUCER]);
+
+        // when
+        let result = validator.validate(&header);
+
+        // then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn rejects_block_whose_id_does_not_match_its_header() {
+        // given
+        let mut header = given_a_header(Some(PRODUCER));
+        header.time += 1;
+        let validator = BlockValidator::new(vec![PRODUCER]);
+
+        // when
+        let result = validator.validate(&header);
+
+        // then
+        assert!(matches!(
+            result,
+            Err(BlockValidationError::IdMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_block_of_unauthorized_producer() {
+        // given
+        let header = given_a_header(Some([2; 64]));
+        let validator = BlockValidator::new(vec![PRODUCER]);
+
+        // when
+        let result = validator.validate(&header);
+
+        // then
+        assert_eq!(
+            result,
+            Err(BlockValidationError::UnauthorizedProducer(hex::encode(
+                [2; 64]
+            )))
+        );
+    }
+
+    #[test]
+    fn rejects_unsigned_block() {
+        // given
+        let header = given_a_header(None);
+        let validator = BlockValidator::new(vec![PRODUCER]);
+
+        // when
+        let result = validator.validate(&header);
+
+        // then
+        assert_eq!(result, Err(BlockValidationError::Unsigned));
+    }
+
+    #[test]
+    fn accepts_any_producer_unless_configured() {
+        // given
+        let validator = BlockValidator::accepting_any_producer();
+
+        // when
+        let signed = validator.validate(&given_a_header(Some([2; 64])));
+        let unsigned = validator.validate(&given_a_header(None));
+
+        // then
+        assert_eq!(signed, Ok(()));
+        assert_eq!(unsigned, Ok(()));
+    }
+
+    #[test]
+    fn checks_block_id_even_when_accepting_any_producer() {
+        // given
+        let mut header = given_a_header(Some(PRODUCER));
+        header.time += 1;
+        let validator = BlockValidator::accepting_any_producer();
+
+        // when
+        let result = validator.validate(&header);
+
+        // then
+        assert!(matches!(
+            result,
+            Err(BlockValidationError::IdMismatch { .. })
+        ));
+    }
+
+    fn given_a_header(producer: Option<[u8; 64]>) -> FuelBlockHeader {
+        let mut header = FuelBlockHeader {
+            producer,
+            ..rand::thread_rng().gen()
+        };
+        header.block.hash = block_id(&header);
+        header
+    }
+}
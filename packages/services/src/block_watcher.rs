@@ -1,23 +1,34 @@
-use std::{num::NonZeroU32, vec};
+use std::{num::NonZeroU32, time::Instant, vec};
 
 use async_trait::async_trait;
+use futures::StreamExt;
 use metrics::{
-    prometheus::{core::Collector, IntGauge, Opts},
+    prometheus::{core::Collector, IntCounter, IntGauge, Opts},
     RegistersMetrics,
 };
-use ports::{storage::Storage, types::FuelBlock};
+use ports::{
+    fuel::BlockStream,
+    storage::Storage,
+    types::{FuelBlock, FuelBlockHeader},
+};
 use tokio::sync::mpsc::Sender;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
 
 use super::Runner;
-use crate::{Error, Result};
+use crate::{BlockValidator, Error, Result};
 
 struct Metrics {
     latest_fuel_block: IntGauge,
+    rejected_fuel_blocks: IntCounter,
 }
 
 impl<A, Db> RegistersMetrics for BlockWatcher<A, Db> {
     fn metrics(&self) -> Vec<Box<dyn Collector>> {
-        vec![Box::new(self.metrics.latest_fuel_block.clone())]
+        vec![
+            Box::new(self.metrics.latest_fuel_block.clone()),
+            Box::new(self.metrics.rejected_fuel_blocks.clone()),
+        ]
     }
 }
 
@@ -25,34 +36,60 @@ impl Default for Metrics {
     fn default() -> Self {
         let latest_fuel_block = IntGauge::with_opts(Opts::new(
             "latest_fuel_block",
-            "The height of the latest fuel block.",
+            "The height of the latest fuel block seen.",
         ))
         .expect("fuel_network_errors metric to be correctly configured");
 
-        Self { latest_fuel_block }
+        let rejected_fuel_blocks = IntCounter::with_opts(Opts::new(
+            "rejected_fuel_blocks",
+            "Number of times a fuel block failed verification and wasn't committed.",
+        ))
+        .expect("rejected_fuel_blocks metric to be correctly configured");
+
+        Self {
+            latest_fuel_block,
+            rejected_fuel_blocks,
+        }
     }
 }
 
+/// Hands every epoch block to the committer. Follows new blocks as the fuel node pushes them,
+/// falling back to polling for the latest block should the node not offer a subscription or it
+/// drop. Polls are skipped while the next epoch block isn't due yet.
 pub struct BlockWatcher<A, Db> {
     fuel_adapter: A,
     tx_fuel_block: Sender<FuelBlock>,
     storage: Db,
+    validator: BlockValidator,
     commit_interval: NonZeroU32,
+    max_epochs_per_poll: NonZeroU32,
+    cancel_token: CancellationToken,
+    // when the latest block was last polled for, along with its height
+    last_poll: Option<(Instant, u32)>,
+    skip_polls_until: Option<Instant>,
     metrics: Metrics,
 }
 
 impl<A, Db> BlockWatcher<A, Db> {
     pub fn new(
         commit_interval: NonZeroU32,
+        max_epochs_per_poll: NonZeroU32,
         tx_fuel_block: Sender<FuelBlock>,
         fuel_adapter: A,
         storage: Db,
+        validator: BlockValidator,
+        cancel_token: CancellationToken,
     ) -> Self {
         Self {
             commit_interval,
+            max_epochs_per_poll,
             fuel_adapter,
             tx_fuel_block,
             storage,
+            validator,
+            cancel_token,
+            last_poll: None,
+            skip_polls_until: None,
             metrics: Metrics::default(),
         }
     }
@@ -62,26 +99,102 @@ where
     A: ports::fuel::Api,
     Db: Storage,
 {
-    async fn fetch_latest_block(&self) -> Result<FuelBlock> {
-        let current_block = self.fuel_adapter.latest_block().await?;
+    /// Commits new epoch blocks until the subscription drops or the committer shuts down.
+    async fn follow(&self, mut blocks: BlockStream) -> Result<()> {
+        info!("following new fuel blocks");
+
+        loop {
+            let block = tokio::select! {
+                block = blocks.next() => block,
+                () = self.cancel_token.cancelled() => return Ok(()),
+            };
+            let Some(block) = block else {
+                warn!("fuel block subscription dropped, polling for new blocks instead");
+                return Ok(());
+            };
+
+            self.commit_epochs_up_to(block?).await?;
+        }
+    }
+
+    async fn poll(&mut self) -> Result<()> {
+        let now = Instant::now();
+        if self.skip_polls_until.is_some_and(|until| now < until) {
+            return Ok(());
+        }
+        self.skip_polls_until = None;
+
+        let latest_block = self.fuel_adapter.latest_block().await?;
+        if self.commit_epochs_up_to(latest_block).await? {
+            self.skip_polls_until = self.next_epoch_due(now, latest_block.height);
+        }
+        self.last_poll = Some((now, latest_block.height));
+
+        Ok(())
+    }
+
+    /// Halfway to when the epoch after `height` is expected, going by how fast blocks were
+    /// produced since the last poll. Only waiting for half of it keeps the epoch from being picked
+    /// up late when blocks start coming in faster.
+    fn next_epoch_due(&self, now: Instant, height: u32) -> Option<Instant> {
+        let (last_polled_at, last_height) = self.last_poll?;
+        let produced = height.checked_sub(last_height).filter(|&n| n > 0)?;
+        let block_time = now.duration_since(last_polled_at) / produced;
+
+        let blocks_left = self.commit_interval.get() - height % self.commit_interval;
+
+        Some(now + block_time * blocks_left / 2)
+    }
 
+    /// Hands the epoch blocks up to `latest_block` not yet submitted to the committer. Returns
+    /// whether there are none left, backfilling being limited to `max_epochs_per_poll` at a time.
+    async fn commit_epochs_up_to(&self, latest_block: FuelBlock) -> Result<bool> {
         self.metrics
             .latest_fuel_block
-            .set(i64::from(current_block.height));
+            .set(i64::from(latest_block.height));
+
+        let current_epoch_block_height = self.epoch_block_height(latest_block.height);
+        let epochs = self.epochs_to_commit(current_epoch_block_height).await?;
+        let caught_up = epochs
+            .last()
+            .map_or(true, |&height| height == current_epoch_block_height);
+
+        for height in epochs {
+            // kept so that the block can be verified and audited later on
+            let header = self.fetch_block_header(height).await?;
+            // later epochs are held back too, they can't be committed past a rejected one
+            self.validate(&header)?;
+            self.storage.insert_fuel_block_header(header).await?;
+
+            self.tx_fuel_block
+                .send(header.block)
+                .await
+                .map_err(|e| Error::Other(e.to_string()))?;
+        }
 
-        Ok(current_block)
+        Ok(caught_up)
     }
 
-    async fn check_if_stale(&self, block_height: u32) -> Result<bool> {
+    /// Epoch heights not yet submitted, oldest first. Epochs missed while the committer was down
+    /// are backfilled, at most `max_epochs_per_poll` at a time.
+    async fn epochs_to_commit(&self, current_epoch_block_height: u32) -> Result<Vec<u32>> {
         let Some(submitted_height) = self.last_submitted_block_height().await? else {
-            return Ok(false);
+            return Ok(vec![current_epoch_block_height]);
         };
 
-        Ok(submitted_height >= block_height)
+        let next_epoch_block_height =
+            self.epoch_block_height(submitted_height) + self.commit_interval.get();
+
+        let epochs = (next_epoch_block_height..=current_epoch_block_height)
+            .step_by(self.commit_interval.get() as usize)
+            .take(self.max_epochs_per_poll.get() as usize)
+            .collect();
+
+        Ok(epochs)
     }
 
-    fn current_epoch_block_height(&self, current_block_height: u32) -> u32 {
-        current_block_height - (current_block_height % self.commit_interval)
+    fn epoch_block_height(&self, block_height: u32) -> u32 {
+        block_height - (block_height % self.commit_interval)
     }
 
     async fn last_submitted_block_height(&self) -> Result<Option<u32>> {
@@ -92,16 +205,23 @@ where
             .map(|submission| submission.block.height))
     }
 
-    async fn fetch_block(&self, height: u32) -> Result<FuelBlock> {
+    async fn fetch_block_header(&self, height: u32) -> Result<FuelBlockHeader> {
         self.fuel_adapter
-            .block_at_height(height)
+            .block_header_at_height(height)
             .await?
             .ok_or_else(|| {
-                Error::Other(format!(
+                Error::MissingFuelBlock(format!(
                     "Fuel node could not provide block at height: {height}"
                 ))
             })
     }
+
+    fn validate(&self, header: &FuelBlockHeader) -> Result<()> {
+        self.validator.validate(header).map_err(|err| {
+            self.metrics.rejected_fuel_blocks.inc();
+            Error::RejectedBlock(format!("height {}: {err}", header.block.height))
+        })
+    }
 }
 
 #[async_trait]
@@ -111,25 +231,18 @@ where
     Db: Storage,
 {
     async fn run(&mut self) -> Result<()> {
-        let current_block = self.fetch_latest_block().await?;
-        let current_epoch_block_height = self.current_epoch_block_height(current_block.height);
-
-        if self.check_if_stale(current_epoch_block_height).await? {
-            return Ok(());
+        match self.fuel_adapter.subscribe_to_blocks().await {
+            Ok(Some(blocks)) => {
+                let followed = self.follow(blocks).await;
+                // blocks may have been missed while the subscription was dropping
+                self.skip_polls_until = None;
+                return followed;
+            }
+            Ok(None) => {}
+            Err(err) => warn!("cannot subscribe to new fuel blocks, polling instead: {err}"),
         }
 
-        let block = if current_block.height == current_epoch_block_height {
-            current_block
-        } else {
-            self.fetch_block(current_epoch_block_height).await?
-        };
-
-        self.tx_fuel_block
-            .send(block)
-            .await
-            .map_err(|e| Error::Other(e.to_string()))?;
-
-        Ok(())
+        self.poll().await
     }
 }
 
@@ -144,6 +257,9 @@ mod tests {
     use storage::{Postgres, PostgresProcess};
 
     use super::*;
+    use crate::block_validator::block_id;
+
+    const PRODUCER: [u8; 64] = [1; 64];
 
     #[tokio::test]
     async fn will_fetch_and_propagate_missed_block() {
@@ -156,7 +272,15 @@ mod tests {
 
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submissions(&process, vec![0, 2]).await;
-        let mut block_watcher = BlockWatcher::new(2.try_into().unwrap(), tx, fuel_adapter, db);
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
 
         // when
         block_watcher.run().await.unwrap();
@@ -180,7 +304,15 @@ mod tests {
 
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submissions(&process, vec![0, 2, 4]).await;
-        let mut block_watcher = BlockWatcher::new(2.try_into().unwrap(), tx, fuel_adapter, db);
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
 
         // when
         block_watcher.run().await.unwrap();
@@ -201,7 +333,15 @@ mod tests {
 
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submissions(&process, vec![0, 2, 4, 6]).await;
-        let mut block_watcher = BlockWatcher::new(2.try_into().unwrap(), tx, fuel_adapter, db);
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
 
         // when
         block_watcher.run().await.unwrap();
@@ -222,7 +362,15 @@ mod tests {
 
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submissions(&process, vec![0, 2]).await;
-        let mut block_watcher = BlockWatcher::new(2.try_into().unwrap(), tx, fuel_adapter, db);
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
 
         // when
         block_watcher.run().await.unwrap();
@@ -235,6 +383,157 @@ mod tests {
         assert_eq!(block, announced_block);
     }
 
+    #[tokio::test]
+    async fn stores_the_header_of_propagated_block() {
+        // given
+        let (tx, _rx) = tokio::sync::mpsc::channel(10);
+
+        let block = given_a_block(4);
+        let fuel_adapter = given_fetcher(vec![block]);
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0, 2]).await;
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db.clone(),
+            given_validator(),
+            CancellationToken::new(),
+        );
+
+        // when
+        block_watcher.run().await.unwrap();
+
+        //then
+        let header = db.fuel_block_header(block.hash).await.unwrap().unwrap();
+        assert_eq!(header.block, block);
+    }
+
+    #[tokio::test]
+    async fn rejects_block_not_produced_by_authorized_producer() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+
+        let block = given_a_block(4);
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_subscribe_to_blocks()
+            .returning(|| Ok(None));
+        fuel_adapter
+            .expect_latest_block()
+            .returning(move || Ok(block));
+        fuel_adapter
+            .expect_block_header_at_height()
+            .with(eq(4))
+            .returning(|height| {
+                Ok(Some(FuelBlockHeader {
+                    producer: Some([2; 64]),
+                    ..given_a_header(height)
+                }))
+            });
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0, 2]).await;
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db.clone(),
+            given_validator(),
+            CancellationToken::new(),
+        );
+
+        let registry = Registry::default();
+        block_watcher.register_metrics(&registry);
+
+        // when
+        let result = block_watcher.run().await;
+
+        //then
+        assert!(matches!(result, Err(Error::RejectedBlock(_))));
+        assert!(rx.try_recv().is_err());
+        assert!(db.fuel_block_header(block.hash).await.unwrap().is_none());
+
+        let metrics = registry.gather();
+        let rejected_blocks_metric = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "rejected_fuel_blocks")
+            .and_then(|metric| metric.get_metric().first())
+            .map(Metric::get_counter)
+            .unwrap();
+
+        assert_eq!(rejected_blocks_metric.get_value(), 1f64);
+    }
+
+    #[tokio::test]
+    async fn backfills_missed_epochs_oldest_first() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+
+        let missed_blocks = [given_a_block(4), given_a_block(6)];
+        let latest_block = given_a_block(8);
+        let fuel_adapter = given_fetcher(
+            missed_blocks
+                .iter()
+                .copied()
+                .chain([latest_block])
+                .collect(),
+        );
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0, 2]).await;
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
+
+        // when
+        block_watcher.run().await.unwrap();
+
+        //then
+        let announced_blocks = std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(
+            announced_blocks,
+            vec![missed_blocks[0], missed_blocks[1], latest_block]
+        );
+    }
+
+    #[tokio::test]
+    async fn backfills_at_most_the_configured_number_of_epochs_per_poll() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+
+        let missed_block = given_a_block(4);
+        let fuel_adapter = given_fetcher(vec![missed_block, given_a_block(8)]);
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0, 2]).await;
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            1.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
+
+        // when
+        block_watcher.run().await.unwrap();
+
+        //then
+        let announced_blocks = std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(announced_blocks, vec![missed_block]);
+    }
+
     #[tokio::test]
     async fn updates_block_metric_regardless_if_block_is_published() {
         // given
@@ -244,7 +543,15 @@ mod tests {
 
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submissions(&process, vec![0, 2, 4]).await;
-        let mut block_watcher = BlockWatcher::new(2.try_into().unwrap(), tx, fuel_adapter, db);
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
 
         let registry = Registry::default();
         block_watcher.register_metrics(&registry);
@@ -264,6 +571,82 @@ mod tests {
         assert_eq!(latest_block_metric.get_value(), 5f64);
     }
 
+    #[tokio::test]
+    async fn skips_polls_while_the_next_epoch_block_is_not_due() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_subscribe_to_blocks()
+            .returning(|| Ok(None));
+        let mut latest_heights = vec![110, 100];
+        fuel_adapter
+            .expect_latest_block()
+            .times(2)
+            .returning(move || Ok(given_a_block(latest_heights.pop().unwrap())));
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0]).await;
+        let mut block_watcher = BlockWatcher::new(
+            1000.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
+        block_watcher.run().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        block_watcher.run().await.unwrap();
+
+        // when
+        block_watcher.run().await.unwrap();
+
+        //then
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn commits_epoch_blocks_as_they_get_pushed() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+
+        let epoch_block = given_a_block(4);
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_subscribe_to_blocks()
+            .returning(move || {
+                let pushed = [given_a_block(3), epoch_block].map(Ok);
+                Ok(Some(Box::pin(futures::stream::iter(pushed))))
+            });
+        fuel_adapter
+            .expect_block_header_at_height()
+            .with(eq(4))
+            .returning(|height| Ok(Some(given_a_header(height))));
+        fuel_adapter.expect_latest_block().never();
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![0, 2]).await;
+        let mut block_watcher = BlockWatcher::new(
+            2.try_into().unwrap(),
+            10.try_into().unwrap(),
+            tx,
+            fuel_adapter,
+            db,
+            given_validator(),
+            CancellationToken::new(),
+        );
+
+        // when
+        block_watcher.run().await.unwrap();
+
+        //then
+        let announced_blocks = std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(announced_blocks, vec![epoch_block]);
+    }
+
     async fn db_with_submissions(
         process: &Arc<PostgresProcess>,
         pending_submissions: Vec<u32>,
@@ -278,11 +661,17 @@ mod tests {
 
     fn given_fetcher(available_blocks: Vec<FuelBlock>) -> MockApi {
         let mut fetcher = MockApi::new();
+        fetcher.expect_subscribe_to_blocks().returning(|| Ok(None));
         for block in available_blocks.clone() {
             fetcher
-                .expect_block_at_height()
+                .expect_block_header_at_height()
                 .with(eq(block.height))
-                .returning(move |_| Ok(Some(block)));
+                .returning(move |height| {
+                    Ok(Some(FuelBlockHeader {
+                        block,
+                        ..given_a_header(height)
+                    }))
+                });
         }
         if let Some(block) = available_blocks.into_iter().max_by_key(|el| el.height) {
             fetcher.expect_latest_block().returning(move || Ok(block));
@@ -297,10 +686,31 @@ mod tests {
         submission
     }
 
+    fn given_validator() -> BlockValidator {
+        BlockValidator::new(vec![PRODUCER])
+    }
+
+    fn given_a_header(block_height: u32) -> FuelBlockHeader {
+        let mut header = FuelBlockHeader {
+            block: FuelBlock {
+                hash: Default::default(),
+                height: block_height,
+            },
+            prev_root: [0; 32],
+            time: 0,
+            da_height: 0,
+            transactions_root: [0; 32],
+            message_outbox_root: [0; 32],
+            application_hash: [0; 32],
+            producer: Some(PRODUCER),
+            transactions_count: 0,
+        };
+        header.block.hash = block_id(&header);
+
+        header
+    }
+
     fn given_a_block(block_height: u32) -> FuelBlock {
-        FuelBlock {
-            hash: Default::default(),
-            height: block_height,
-        }
+        given_a_header(block_height).block
     }
 }
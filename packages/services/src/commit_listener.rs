@@ -1,47 +1,79 @@
+use std::num::NonZeroU32;
+
 use async_trait::async_trait;
 use futures::{StreamExt, TryStreamExt};
 use metrics::{
-    prometheus::{core::Collector, IntGauge, Opts},
+    prometheus::{core::Collector, IntCounter, IntGauge, Opts},
     RegistersMetrics,
 };
 use ports::{
     storage::Storage,
-    types::{FuelBlockCommittedOnL1, L1Height},
+    types::{ForeignCommit, FuelBlock, FuelBlockCommittedOnL1, L1Height, U256},
 };
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use super::Runner;
+use crate::Error;
 
-pub struct CommitListener<C, Db> {
+pub struct CommitListener<C, A, Db> {
     contract: C,
+    fuel_adapter: A,
     storage: Db,
+    commit_interval: NonZeroU32,
     metrics: Metrics,
     cancel_token: CancellationToken,
 }
 
-impl<C, Db> CommitListener<C, Db> {
-    pub fn new(contract: C, storage: Db, cancel_token: CancellationToken) -> Self {
+impl<C, A, Db> CommitListener<C, A, Db> {
+    pub fn new(
+        contract: C,
+        fuel_adapter: A,
+        storage: Db,
+        commit_interval: NonZeroU32,
+        cancel_token: CancellationToken,
+    ) -> Self {
         Self {
             contract,
+            fuel_adapter,
             storage,
+            commit_interval,
             metrics: Metrics::default(),
             cancel_token,
         }
     }
 }
 
-impl<C, Db> CommitListener<C, Db>
+impl<C, A, Db> CommitListener<C, A, Db>
 where
     C: ports::l1::Contract,
+    A: ports::fuel::Api,
     Db: Storage,
 {
+    /// Resumes from the last handled event, going further back if an incomplete submission was
+    /// sent before it, so that its commit event isn't missed.
     async fn determine_starting_l1_height(&mut self) -> crate::Result<L1Height> {
-        Ok(self
+        let cursor = match self.storage.l1_event_cursor().await? {
+            Some(cursor) => Some(cursor),
+            // no event was handled since the cursor was introduced
+            None => self
+                .storage
+                .submission_w_latest_block()
+                .await?
+                .map(|submission| submission.submittal_height),
+        };
+
+        let oldest_incomplete = self
             .storage
-            .submission_w_latest_block()
+            .oldest_incomplete_submission()
             .await?
-            .map_or(0u32.into(), |submission| submission.submittal_height))
+            .map(|submission| submission.submittal_height);
+
+        Ok(cursor
+            .into_iter()
+            .chain(oldest_incomplete)
+            .min()
+            .unwrap_or_else(|| 0u32.into()))
     }
 
     async fn handle_block_committed(
@@ -50,9 +82,33 @@ where
     ) -> crate::Result<()> {
         info!("block committed on l1 {committed_on_l1:?}");
 
+        if self
+            .storage
+            .submission(committed_on_l1.fuel_block_hash)
+            .await?
+            .is_some()
+        {
+            self.complete_submission(committed_on_l1).await?;
+        } else {
+            self.record_foreign_commit(committed_on_l1).await?;
+        }
+
+        // only a handled event moves the cursor past it, so that an event failing to be handled
+        // is streamed again when starting over
+        self.storage
+            .advance_l1_event_cursor(committed_on_l1.included_in.height)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn complete_submission(
+        &self,
+        committed_on_l1: FuelBlockCommittedOnL1,
+    ) -> crate::Result<()> {
         let submission = self
             .storage
-            .set_submission_completed(committed_on_l1.fuel_block_hash)
+            .set_submission_completed(committed_on_l1.fuel_block_hash, committed_on_l1.included_in)
             .await?;
 
         self.metrics
@@ -62,6 +118,58 @@ where
         Ok(())
     }
 
+    /// Compares a commit of a block we never submitted with the block the fuel node has at the
+    /// committed height.
+    async fn record_foreign_commit(
+        &self,
+        committed_on_l1: FuelBlockCommittedOnL1,
+    ) -> crate::Result<()> {
+        let height = self.fuel_block_height(committed_on_l1.commit_height)?;
+        let block = FuelBlock {
+            hash: committed_on_l1.fuel_block_hash,
+            height,
+        };
+
+        let matches_fuel_chain = self
+            .fuel_adapter
+            .block_at_height(height)
+            .await?
+            .map(|fuel_block| fuel_block.hash == block.hash);
+
+        self.metrics.foreign_commits.inc();
+        match matches_fuel_chain {
+            Some(true) => warn!("{block:?} was committed by someone else"),
+            Some(false) => {
+                error!("{block:?} was committed by someone else, but the fuel node has a different block at that height");
+                self.metrics.foreign_commit_conflicts.inc();
+            }
+            None => warn!(
+                "{block:?} was committed by someone else, the fuel node doesn't know about its height"
+            ),
+        }
+
+        self.storage
+            .insert_foreign_commit(ForeignCommit {
+                block,
+                included_in: committed_on_l1.included_in,
+                matches_fuel_chain,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    fn fuel_block_height(&self, commit_height: U256) -> crate::Result<u32> {
+        u32::try_from(commit_height)
+            .ok()
+            .and_then(|commit_height| commit_height.checked_mul(self.commit_interval.get()))
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "commit height {commit_height} doesn't correspond to a fuel block height"
+                ))
+            })
+    }
+
     fn log_if_error(result: crate::Result<()>) {
         if let Err(error) = result {
             error!("Received an error from block commit event stream: {error}");
@@ -70,9 +178,10 @@ where
 }
 
 #[async_trait]
-impl<C, Db> Runner for CommitListener<C, Db>
+impl<C, A, Db> Runner for CommitListener<C, A, Db>
 where
     C: ports::l1::Contract,
+    A: ports::fuel::Api,
     Db: Storage,
 {
     async fn run(&mut self) -> crate::Result<()> {
@@ -95,11 +204,17 @@ where
 #[derive(Clone)]
 struct Metrics {
     latest_committed_block: IntGauge,
+    foreign_commits: IntCounter,
+    foreign_commit_conflicts: IntCounter,
 }
 
-impl<E, Db> RegistersMetrics for CommitListener<E, Db> {
+impl<E, A, Db> RegistersMetrics for CommitListener<E, A, Db> {
     fn metrics(&self) -> Vec<Box<dyn Collector>> {
-        vec![Box::new(self.metrics.latest_committed_block.clone())]
+        vec![
+            Box::new(self.metrics.latest_committed_block.clone()),
+            Box::new(self.metrics.foreign_commits.clone()),
+            Box::new(self.metrics.foreign_commit_conflicts.clone()),
+        ]
     }
 }
 
@@ -111,8 +226,22 @@ impl Default for Metrics {
         ))
         .expect("latest_committed_block metric to be correctly configured");
 
+        let foreign_commits = IntCounter::with_opts(Opts::new(
+            "foreign_commits",
+            "Number of commit events seen for fuel blocks this committer didn't submit.",
+        ))
+        .expect("foreign_commits metric to be correctly configured");
+
+        let foreign_commit_conflicts = IntCounter::with_opts(Opts::new(
+            "foreign_commit_conflicts",
+            "Number of foreign commits of a block the fuel node doesn't have at the committed height.",
+        ))
+        .expect("foreign_commit_conflicts metric to be correctly configured");
+
         Self {
             latest_committed_block,
+            foreign_commits,
+            foreign_commit_conflicts,
         }
     }
 }
@@ -126,9 +255,12 @@ mod tests {
     };
     use mockall::predicate;
     use ports::{
+        fuel::MockApi,
         l1::{MockContract, MockEventStreamer},
         storage::Storage,
-        types::{BlockSubmission, FuelBlockCommittedOnL1, L1Height, U256},
+        types::{
+            BlockSubmission, ForeignCommit, FuelBlock, FuelBlockCommittedOnL1, L1Height, U256,
+        },
     };
     use rand::Rng;
     use storage::{Postgres, PostgresProcess};
@@ -152,8 +284,13 @@ mod tests {
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submission(&process, submission).await;
 
-        let mut commit_listener =
-            CommitListener::new(contract, db.clone(), CancellationToken::default());
+        let mut commit_listener = CommitListener::new(
+            contract,
+            MockApi::new(),
+            db.clone(),
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
 
         // when
         commit_listener.run().await.unwrap();
@@ -180,7 +317,13 @@ mod tests {
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submission(&process, submission).await;
 
-        let mut commit_listener = CommitListener::new(contract, db, CancellationToken::default());
+        let mut commit_listener = CommitListener::new(
+            contract,
+            MockApi::new(),
+            db,
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
 
         let registry = Registry::new();
         commit_listener.register_metrics(&registry);
@@ -221,8 +364,13 @@ mod tests {
         let process = PostgresProcess::shared().await.unwrap();
         let db = db_with_submission(&process, incoming_block.clone()).await;
 
-        let mut commit_listener =
-            CommitListener::new(contract, db.clone(), CancellationToken::default());
+        let mut commit_listener = CommitListener::new(
+            contract,
+            given_unreachable_fuel_adapter(),
+            db.clone(),
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
 
         // when
         commit_listener.run().await.unwrap();
@@ -232,10 +380,246 @@ mod tests {
         assert_eq!(
             BlockSubmission {
                 completed: true,
+                committed_in: latest_submission.committed_in,
+                confirmed: false,
                 ..incoming_block.clone()
             },
             latest_submission
         );
+        assert!(latest_submission.committed_in.is_some());
+    }
+
+    #[tokio::test]
+    async fn listener_advances_the_l1_event_cursor() {
+        // given
+        let mut rng = rand::thread_rng();
+        let submission = BlockSubmission {
+            completed: false,
+            ..rng.gen()
+        };
+
+        let event = FuelBlockCommittedOnL1 {
+            fuel_block_hash: submission.block.hash,
+            commit_height: U256::default(),
+            included_in: rng.gen(),
+        };
+        let contract =
+            given_contract_with_committed_events(vec![event], submission.submittal_height);
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submission(&process, submission).await;
+
+        let mut commit_listener = CommitListener::new(
+            contract,
+            MockApi::new(),
+            db.clone(),
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        //then
+        assert_eq!(
+            db.l1_event_cursor().await.unwrap(),
+            Some(event.included_in.height)
+        );
+    }
+
+    #[tokio::test]
+    async fn listener_wont_advance_the_l1_event_cursor_past_an_unhandled_event() {
+        // given
+        let event = given_commit_event(rand::thread_rng().gen(), 2);
+        let contract = given_contract_with_committed_events(vec![event], 0u32.into());
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut commit_listener = CommitListener::new(
+            contract,
+            given_unreachable_fuel_adapter(),
+            db.clone(),
+            10.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        //then
+        assert_eq!(db.l1_event_cursor().await.unwrap(), None);
+        assert_eq!(
+            db.foreign_commit(event.fuel_block_hash).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn listener_resumes_from_the_l1_event_cursor() {
+        // given
+        let mut rng = rand::thread_rng();
+        let submission = BlockSubmission {
+            completed: true,
+            submittal_height: 5u32.into(),
+            ..rng.gen()
+        };
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submission(&process, submission).await;
+        db.advance_l1_event_cursor(42u32.into()).await.unwrap();
+
+        let contract = given_contract_with_events(vec![], 42u32.into());
+        let mut commit_listener = CommitListener::new(
+            contract,
+            MockApi::new(),
+            db,
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        // then the mock asserts the starting height
+    }
+
+    #[tokio::test]
+    async fn listener_goes_back_to_the_oldest_incomplete_submission() {
+        // given
+        let mut rng = rand::thread_rng();
+        let incomplete = BlockSubmission {
+            completed: false,
+            submittal_height: 5u32.into(),
+            ..rng.gen()
+        };
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submission(&process, incomplete).await;
+        db.advance_l1_event_cursor(42u32.into()).await.unwrap();
+
+        let contract = given_contract_with_events(vec![], 5u32.into());
+        let mut commit_listener = CommitListener::new(
+            contract,
+            MockApi::new(),
+            db,
+            1.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        // then the mock asserts the starting height
+    }
+
+    #[tokio::test]
+    async fn foreign_commit_matching_the_fuel_chain_is_recorded() {
+        // given
+        let mut rng = rand::thread_rng();
+        let block = FuelBlock {
+            hash: rng.gen(),
+            height: 20,
+        };
+        let event = given_commit_event(block.hash, 2);
+        let contract = given_contract_with_committed_events(vec![event], 0u32.into());
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut commit_listener = CommitListener::new(
+            contract,
+            given_fuel_adapter_w_block(block),
+            db.clone(),
+            10.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        //then
+        let foreign_commit = db.foreign_commit(block.hash).await.unwrap();
+        assert_eq!(
+            foreign_commit,
+            Some(ForeignCommit {
+                block,
+                included_in: event.included_in,
+                matches_fuel_chain: Some(true),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn foreign_commit_conflicting_with_the_fuel_chain_is_counted() {
+        // given
+        let mut rng = rand::thread_rng();
+        let fuel_block = FuelBlock {
+            hash: rng.gen(),
+            height: 20,
+        };
+        let event = given_commit_event(rng.gen(), 2);
+        let contract = given_contract_with_committed_events(vec![event], 0u32.into());
+
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut commit_listener = CommitListener::new(
+            contract,
+            given_fuel_adapter_w_block(fuel_block),
+            db.clone(),
+            10.try_into().unwrap(),
+            CancellationToken::default(),
+        );
+
+        let registry = Registry::new();
+        commit_listener.register_metrics(&registry);
+
+        // when
+        commit_listener.run().await.unwrap();
+
+        //then
+        let foreign_commit = db.foreign_commit(event.fuel_block_hash).await.unwrap();
+        assert_eq!(
+            foreign_commit.and_then(|commit| commit.matches_fuel_chain),
+            Some(false)
+        );
+
+        let metrics = registry.gather();
+        let conflicts = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "foreign_commit_conflicts")
+            .and_then(|metric| metric.get_metric().first())
+            .map(Metric::get_counter)
+            .unwrap();
+        assert_eq!(conflicts.get_value(), 1.0);
+    }
+
+    fn given_commit_event(fuel_block_hash: [u8; 32], commit_height: u32) -> FuelBlockCommittedOnL1 {
+        FuelBlockCommittedOnL1 {
+            fuel_block_hash,
+            commit_height: commit_height.into(),
+            included_in: rand::thread_rng().gen(),
+        }
+    }
+
+    fn given_fuel_adapter_w_block(block: FuelBlock) -> MockApi {
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_block_at_height()
+            .with(predicate::eq(block.height))
+            .returning(move |_| Ok(Some(block)));
+
+        fuel_adapter
+    }
+
+    fn given_unreachable_fuel_adapter() -> MockApi {
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_block_at_height()
+            .returning(|_| Err(ports::fuel::Error::Network("unreachable".to_string())));
+
+        fuel_adapter
     }
 
     async fn db_with_submission(
@@ -252,6 +636,22 @@ mod tests {
     fn given_contract_with_events(
         events: Vec<[u8; 32]>,
         starting_from_height: L1Height,
+    ) -> MockContract {
+        let events = events
+            .into_iter()
+            .map(|block_hash| FuelBlockCommittedOnL1 {
+                fuel_block_hash: block_hash,
+                commit_height: U256::default(),
+                included_in: rand::thread_rng().gen(),
+            })
+            .collect();
+
+        given_contract_with_committed_events(events, starting_from_height)
+    }
+
+    fn given_contract_with_committed_events(
+        events: Vec<FuelBlockCommittedOnL1>,
+        starting_from_height: L1Height,
     ) -> MockContract {
         let mut contract = MockContract::new();
 
@@ -264,16 +664,9 @@ mod tests {
         contract
     }
 
-    fn given_event_streamer_w_events(events: Vec<[u8; 32]>) -> MockEventStreamer {
+    fn given_event_streamer_w_events(events: Vec<FuelBlockCommittedOnL1>) -> MockEventStreamer {
         let mut streamer = MockEventStreamer::new();
-        let events = events
-            .into_iter()
-            .map(|block_hash| FuelBlockCommittedOnL1 {
-                fuel_block_hash: block_hash,
-                commit_height: U256::default(),
-            })
-            .map(Ok)
-            .collect::<Vec<_>>();
+        let events = events.into_iter().map(Ok).collect::<Vec<_>>();
 
         streamer
             .expect_establish_stream()
@@ -0,0 +1,229 @@
+use std::num::NonZeroU32;
+
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntGauge, Opts},
+    RegistersMetrics,
+};
+use ports::{
+    storage::Storage,
+    types::{BlockSubmission, CommitVerification},
+};
+use tracing::error;
+
+use super::Runner;
+use crate::Result;
+
+/// Reads back from the state contract what it holds for the most recent completed submissions,
+/// recording whether it matches what we submitted and whether the contract considers it
+/// finalized.
+pub struct CommitVerifier<C, Db> {
+    contract: C,
+    storage: Db,
+    commit_interval: NonZeroU32,
+    submissions_to_check: u32,
+    metrics: Metrics,
+}
+
+impl<C, Db> CommitVerifier<C, Db> {
+    pub fn new(
+        contract: C,
+        storage: Db,
+        commit_interval: NonZeroU32,
+        submissions_to_check: u32,
+    ) -> Self {
+        Self {
+            contract,
+            storage,
+            commit_interval,
+            submissions_to_check,
+            metrics: Metrics::default(),
+        }
+    }
+}
+
+impl<C, Db> CommitVerifier<C, Db>
+where
+    C: ports::l1::Contract,
+    Db: Storage,
+{
+    async fn verify(&self, submission: &BlockSubmission) -> Result<CommitVerification> {
+        let commit_height = submission.block.height / self.commit_interval;
+        let committed_hash = self
+            .contract
+            .block_hash_at_commit_height(commit_height)
+            .await?;
+
+        let mut verification = CommitVerification {
+            fuel_block_hash: submission.block.hash,
+            committed_hash: (committed_hash != [0; 32]).then_some(committed_hash),
+            finalized: false,
+        };
+
+        if verification.matches() {
+            verification.finalized = self.contract.finalized(submission.block).await?;
+        }
+
+        Ok(verification)
+    }
+}
+
+#[async_trait]
+impl<C, Db> Runner for CommitVerifier<C, Db>
+where
+    C: ports::l1::Contract,
+    Db: Storage,
+{
+    async fn run(&mut self) -> Result<()> {
+        let mut mismatches: i64 = 0;
+
+        for submission in self
+            .storage
+            .latest_submissions(self.submissions_to_check)
+            .await?
+            .into_iter()
+            .filter(|submission| submission.completed)
+        {
+            let verification = self.verify(&submission).await?;
+
+            if !verification.matches() {
+                error!(
+                    "state contract holds {:?} at the commit height of the submitted {:?}",
+                    verification.committed_hash.map(hex::encode),
+                    submission.block
+                );
+                mismatches += 1;
+            }
+
+            self.storage.set_commit_verification(verification).await?;
+        }
+
+        self.metrics.onchain_commit_mismatches.set(mismatches);
+
+        Ok(())
+    }
+}
+
+impl<C, Db> RegistersMetrics for CommitVerifier<C, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.metrics.onchain_commit_mismatches.clone())]
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    onchain_commit_mismatches: IntGauge,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let onchain_commit_mismatches = IntGauge::with_opts(Opts::new(
+            "onchain_commit_mismatches",
+            "Number of recent completed submissions whose hash differs from the one the state contract holds.",
+        ))
+        .expect("onchain_commit_mismatches metric to be correctly configured");
+
+        Self {
+            onchain_commit_mismatches,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics::prometheus::{proto::Metric, Registry};
+    use mockall::predicate;
+    use ports::l1::MockContract;
+    use rand::Rng;
+    use storage::PostgresProcess;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn records_finalized_commit() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let submission = given_completed_submission(20);
+        db.insert(submission.clone()).await.unwrap();
+
+        let mut contract = given_contract_holding(2, submission.block.hash);
+        contract
+            .expect_finalized()
+            .with(predicate::eq(submission.block))
+            .returning(|_| Ok(true));
+
+        let mut verifier = CommitVerifier::new(contract, db.clone(), 10.try_into().unwrap(), 5);
+
+        // when
+        verifier.run().await.unwrap();
+
+        // then
+        let verification = db.commit_verification(submission.block.hash).await.unwrap();
+        assert_eq!(
+            verification,
+            Some(CommitVerification {
+                fuel_block_hash: submission.block.hash,
+                committed_hash: Some(submission.block.hash),
+                finalized: true,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn flags_commit_the_contract_disagrees_with() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let submission = given_completed_submission(20);
+        db.insert(submission.clone()).await.unwrap();
+
+        let mut contract = given_contract_holding(2, rand::thread_rng().gen());
+        contract.expect_finalized().never();
+
+        let mut verifier = CommitVerifier::new(contract, db.clone(), 10.try_into().unwrap(), 5);
+
+        let registry = Registry::new();
+        verifier.register_metrics(&registry);
+
+        // when
+        verifier.run().await.unwrap();
+
+        // then
+        let verification = db
+            .commit_verification(submission.block.hash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!verification.matches());
+        assert!(!verification.finalized);
+
+        let metrics = registry.gather();
+        let mismatches = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "onchain_commit_mismatches")
+            .and_then(|metric| metric.get_metric().first())
+            .map(Metric::get_gauge)
+            .unwrap();
+        assert_eq!(mismatches.get_value(), 1f64);
+    }
+
+    fn given_contract_holding(commit_height: u32, block_hash: [u8; 32]) -> MockContract {
+        let mut contract = MockContract::new();
+        contract
+            .expect_block_hash_at_commit_height()
+            .with(predicate::eq(commit_height))
+            .returning(move |_| Ok(block_hash));
+
+        contract
+    }
+
+    fn given_completed_submission(fuel_block_height: u32) -> BlockSubmission {
+        let mut submission = BlockSubmission {
+            completed: true,
+            ..rand::thread_rng().gen()
+        };
+        submission.block.height = fuel_block_height;
+        submission
+    }
+}
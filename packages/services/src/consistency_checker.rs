@@ -0,0 +1,228 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntGauge, Opts},
+    RegistersMetrics,
+};
+use ports::storage::Storage;
+use tracing::error;
+
+use super::Runner;
+use crate::Result;
+
+/// Shared flag telling whether the fuel node disagrees with the blocks we've submitted.
+#[derive(Debug, Clone, Default)]
+pub struct FuelChainConsistency {
+    mismatch_detected: Arc<AtomicBool>,
+}
+
+impl FuelChainConsistency {
+    #[must_use]
+    pub fn mismatch_detected(&self) -> bool {
+        self.mismatch_detected.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_mismatch_detected(&self, detected: bool) {
+        self.mismatch_detected.store(detected, Ordering::Relaxed);
+    }
+}
+
+/// Compares the most recent submissions against the blocks the fuel node reports at the same
+/// heights. A differing hash means the fuel chain was reorged after we committed to it.
+pub struct ConsistencyChecker<A, Db> {
+    fuel_adapter: A,
+    storage: Db,
+    submissions_to_check: u32,
+    consistency: FuelChainConsistency,
+    metrics: Metrics,
+}
+
+impl<A, Db> ConsistencyChecker<A, Db> {
+    pub fn new(fuel_adapter: A, storage: Db, submissions_to_check: u32) -> Self {
+        Self {
+            fuel_adapter,
+            storage,
+            submissions_to_check,
+            consistency: FuelChainConsistency::default(),
+            metrics: Metrics::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn consistency(&self) -> FuelChainConsistency {
+        self.consistency.clone()
+    }
+}
+
+impl<A, Db> ConsistencyChecker<A, Db>
+where
+    A: ports::fuel::Api,
+    Db: Storage,
+{
+    async fn count_mismatches(&self) -> Result<usize> {
+        let mut mismatches = 0;
+
+        for submission in self
+            .storage
+            .latest_submissions(self.submissions_to_check)
+            .await?
+        {
+            let height = submission.block.height;
+
+            // the node not knowing about the block isn't proof of a different chain
+            let Some(block) = self.fuel_adapter.block_at_height(height).await? else {
+                continue;
+            };
+
+            if block.hash != submission.block.hash {
+                error!(
+                    "fuel node reports {block:?} at height {height}, but {:?} was submitted",
+                    submission.block
+                );
+                mismatches += 1;
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
+
+#[async_trait]
+impl<A, Db> Runner for ConsistencyChecker<A, Db>
+where
+    A: ports::fuel::Api,
+    Db: Storage,
+{
+    async fn run(&mut self) -> Result<()> {
+        let mismatches = self.count_mismatches().await?;
+
+        self.metrics
+            .fuel_block_hash_mismatches
+            .set(mismatches.try_into().unwrap_or(i64::MAX));
+        self.consistency.set_mismatch_detected(mismatches > 0);
+
+        Ok(())
+    }
+}
+
+impl<A, Db> RegistersMetrics for ConsistencyChecker<A, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.metrics.fuel_block_hash_mismatches.clone())]
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    fuel_block_hash_mismatches: IntGauge,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let fuel_block_hash_mismatches = IntGauge::with_opts(Opts::new(
+            "fuel_block_hash_mismatches",
+            "Number of recent submissions whose hash differs from the block the fuel node reports at the same height.",
+        ))
+        .expect("fuel_block_hash_mismatches metric to be correctly configured");
+
+        Self {
+            fuel_block_hash_mismatches,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics::prometheus::{proto::Metric, Registry};
+    use mockall::predicate;
+    use ports::{
+        fuel::MockApi,
+        types::{BlockSubmission, FuelBlock},
+    };
+    use rand::Rng;
+    use storage::{Postgres, PostgresProcess};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn flags_submission_the_fuel_node_disagrees_with() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let submission = given_submission(10);
+        let db = db_with_submissions(&process, vec![submission.clone()]).await;
+
+        let fuel_adapter = given_fuel_adapter_w_block(FuelBlock {
+            hash: [0; 32],
+            ..submission.block
+        });
+        let mut checker = ConsistencyChecker::new(fuel_adapter, db, 5);
+        let consistency = checker.consistency();
+
+        let registry = Registry::new();
+        checker.register_metrics(&registry);
+
+        // when
+        checker.run().await.unwrap();
+
+        // then
+        assert!(consistency.mismatch_detected());
+
+        let metrics = registry.gather();
+        let mismatches = metrics
+            .iter()
+            .find(|metric| metric.get_name() == "fuel_block_hash_mismatches")
+            .and_then(|metric| metric.get_metric().first())
+            .map(Metric::get_gauge)
+            .unwrap();
+        assert_eq!(mismatches.get_value(), 1f64);
+    }
+
+    #[tokio::test]
+    async fn wont_flag_submission_matching_the_fuel_node() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let submission = given_submission(10);
+        let db = db_with_submissions(&process, vec![submission.clone()]).await;
+
+        let fuel_adapter = given_fuel_adapter_w_block(submission.block);
+        let mut checker = ConsistencyChecker::new(fuel_adapter, db, 5);
+        let consistency = checker.consistency();
+
+        // when
+        checker.run().await.unwrap();
+
+        // then
+        assert!(!consistency.mismatch_detected());
+    }
+
+    fn given_fuel_adapter_w_block(block: FuelBlock) -> MockApi {
+        let mut fuel_adapter = MockApi::new();
+        fuel_adapter
+            .expect_block_at_height()
+            .with(predicate::eq(block.height))
+            .returning(move |_| Ok(Some(block)));
+
+        fuel_adapter
+    }
+
+    fn given_submission(fuel_block_height: u32) -> BlockSubmission {
+        let mut submission: BlockSubmission = rand::thread_rng().gen();
+        submission.block.height = fuel_block_height;
+        submission
+    }
+
+    async fn db_with_submissions(
+        process: &PostgresProcess,
+        submissions: Vec<BlockSubmission>,
+    ) -> Postgres {
+        let db = process.create_random_db().await.unwrap();
+        for submission in submissions {
+            db.insert(submission).await.unwrap();
+        }
+
+        db
+    }
+}
@@ -0,0 +1,341 @@
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntCounter, Opts},
+    RegistersMetrics,
+};
+use ports::{
+    storage::Storage,
+    types::{BlockSubmission, FuelBlock, L1Block, L1Height},
+};
+use tokio::sync::mpsc::Sender;
+use tracing::{info, warn};
+
+use super::Runner;
+use crate::{Error, Result};
+
+/// Watches completed submissions until the L1 block containing their commit event is
+/// `finality_depth` blocks deep. Should that block get reorged out in the meantime the submission
+/// is marked as incomplete again and its fuel block re-enqueued for committing, unless the commit
+/// made it into another block.
+pub struct FinalityTracker<A, Db> {
+    l1: A,
+    storage: Db,
+    tx_fuel_block: Sender<FuelBlock>,
+    finality_depth: u32,
+    metrics: Metrics,
+}
+
+impl<A, Db> FinalityTracker<A, Db> {
+    pub fn new(l1: A, storage: Db, tx_fuel_block: Sender<FuelBlock>, finality_depth: u32) -> Self {
+        Self {
+            l1,
+            storage,
+            tx_fuel_block,
+            finality_depth,
+            metrics: Metrics::default(),
+        }
+    }
+}
+
+impl<A, Db> FinalityTracker<A, Db>
+where
+    A: ports::l1::Api + ports::l1::Contract,
+    Db: Storage,
+{
+    async fn check_unconfirmed_submissions(&self) -> Result<()> {
+        let current_height = self.l1.get_block_number().await?;
+
+        for submission in self.storage.unconfirmed_submissions().await? {
+            let Some(committed_in) = submission.committed_in else {
+                // nothing to compare against, can only take the commit as is
+                self.storage
+                    .set_submission_confirmed(submission.block.hash)
+                    .await?;
+                continue;
+            };
+
+            if self.still_canonical(committed_in).await? {
+                if self.is_final(committed_in, current_height) {
+                    self.storage
+                        .set_submission_confirmed(submission.block.hash)
+                        .await?;
+                    info!("commit of {:?} is final", submission.block);
+                }
+            } else {
+                self.handle_reorg(submission, current_height).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn still_canonical(&self, block: L1Block) -> Result<bool> {
+        let canonical_hash = self.l1.block_hash_at(block.height).await?;
+        Ok(canonical_hash == Some(block.hash))
+    }
+
+    fn is_final(&self, block: L1Block, current_height: L1Height) -> bool {
+        u64::from(current_height).saturating_sub(block.height.into())
+            >= u64::from(self.finality_depth)
+    }
+
+    async fn handle_reorg(
+        &self,
+        submission: BlockSubmission,
+        current_height: L1Height,
+    ) -> Result<()> {
+        self.metrics.reorged_commits.inc();
+
+        // the commit tx can't have been included before it was sent
+        let reincluded = self
+            .l1
+            .commit_event(submission.block, submission.submittal_height)
+            .await?;
+
+        match reincluded {
+            Some(committed_on_l1) => {
+                warn!(
+                    "commit of {:?} in {:?} got reorged out, it was included again in {:?}",
+                    submission.block, submission.committed_in, committed_on_l1.included_in
+                );
+                self.storage
+                    .set_submission_completed(submission.block.hash, committed_on_l1.included_in)
+                    .await?;
+
+                Ok(())
+            }
+            None => self.roll_back(submission, current_height).await,
+        }
+    }
+
+    async fn roll_back(&self, submission: BlockSubmission, current_height: L1Height) -> Result<()> {
+        warn!(
+            "commit of {:?} in {:?} got reorged out, committing again",
+            submission.block, submission.committed_in
+        );
+
+        let submission = self
+            .storage
+            .revert_submission_completion(submission.block.hash, current_height)
+            .await?;
+
+        self.tx_fuel_block
+            .send(submission.block)
+            .await
+            .map_err(|e| Error::Other(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<A, Db> Runner for FinalityTracker<A, Db>
+where
+    A: ports::l1::Api + ports::l1::Contract,
+    Db: Storage,
+{
+    async fn run(&mut self) -> Result<()> {
+        self.check_unconfirmed_submissions().await
+    }
+}
+
+impl<A, Db> RegistersMetrics for FinalityTracker<A, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        self.metrics.metrics()
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    reorged_commits: IntCounter,
+}
+
+impl RegistersMetrics for Metrics {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.reorged_commits.clone())]
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let reorged_commits = IntCounter::with_opts(Opts::new(
+            "reorged_commits",
+            "Number of commit events that were removed from L1 by a reorg.",
+        ))
+        .expect("reorged_commits metric to be correctly configured");
+
+        Self { reorged_commits }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use ports::types::FuelBlockCommittedOnL1;
+    use rand::Rng;
+    use storage::{Postgres, PostgresProcess};
+
+    use super::*;
+    use crate::test_utils::MockL1;
+
+    #[tokio::test]
+    async fn confirms_commit_buried_deep_enough() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let committed_in = given_l1_block(10);
+        let (db, submission) = db_with_completed_submission(&process, committed_in).await;
+
+        let l1 = given_l1(15, committed_in.height, Some(committed_in.hash));
+        let mut tracker = FinalityTracker::new(l1, db.clone(), tx, 5);
+
+        // when
+        tracker.run().await.unwrap();
+
+        // then
+        assert!(db.unconfirmed_submissions().await.unwrap().is_empty());
+        assert!(
+            db.submission(submission.block.hash)
+                .await
+                .unwrap()
+                .unwrap()
+                .confirmed
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn wont_confirm_commit_before_finality_depth() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let committed_in = given_l1_block(11);
+        let (db, submission) = db_with_completed_submission(&process, committed_in).await;
+
+        let l1 = given_l1(15, committed_in.height, Some(committed_in.hash));
+        let mut tracker = FinalityTracker::new(l1, db.clone(), tx, 5);
+
+        // when
+        tracker.run().await.unwrap();
+
+        // then
+        assert_eq!(
+            db.unconfirmed_submissions().await.unwrap(),
+            vec![submission]
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn rolls_back_commit_that_was_reorged_out() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let committed_in = given_l1_block(10);
+        let (db, submission) = db_with_completed_submission(&process, committed_in).await;
+
+        let mut l1 = given_l1(15, committed_in.height, Some([0; 32]));
+        l1.contract
+            .expect_commit_event()
+            .with(
+                predicate::eq(submission.block),
+                predicate::eq(submission.submittal_height),
+            )
+            .returning(|_, _| Ok(None));
+        let mut tracker = FinalityTracker::new(l1, db.clone(), tx, 5);
+
+        // when
+        tracker.run().await.unwrap();
+
+        // then
+        assert_eq!(rx.try_recv().unwrap(), submission.block);
+        assert_eq!(
+            db.submission(submission.block.hash).await.unwrap(),
+            Some(BlockSubmission {
+                completed: false,
+                committed_in: None,
+                submittal_height: 15u32.into(),
+                attempts: submission.attempts + 1,
+                ..submission
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_track_of_commit_included_again_after_reorg() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let committed_in = given_l1_block(10);
+        let (db, submission) = db_with_completed_submission(&process, committed_in).await;
+
+        let included_again_in = given_l1_block(11);
+        let mut l1 = given_l1(15, committed_in.height, Some([0; 32]));
+        l1.contract
+            .expect_commit_event()
+            .returning(move |block, _| {
+                Ok(Some(FuelBlockCommittedOnL1 {
+                    fuel_block_hash: block.hash,
+                    commit_height: 0u32.into(),
+                    included_in: included_again_in,
+                }))
+            });
+        let mut tracker = FinalityTracker::new(l1, db.clone(), tx, 5);
+
+        // when
+        tracker.run().await.unwrap();
+
+        // then
+        assert!(rx.try_recv().is_err());
+        assert_eq!(
+            db.submission(submission.block.hash).await.unwrap(),
+            Some(BlockSubmission {
+                committed_in: Some(included_again_in),
+                ..submission
+            })
+        );
+    }
+
+    fn given_l1(
+        current_height: u32,
+        commit_height: L1Height,
+        canonical_hash: Option<[u8; 32]>,
+    ) -> MockL1 {
+        let mut l1 = MockL1::new();
+        l1.api
+            .expect_get_block_number()
+            .returning(move || Ok(current_height.into()));
+        l1.api
+            .expect_block_hash_at()
+            .with(predicate::eq(commit_height))
+            .returning(move |_| Ok(canonical_hash));
+        l1
+    }
+
+    fn given_l1_block(height: u32) -> L1Block {
+        L1Block {
+            height: height.into(),
+            hash: rand::thread_rng().gen(),
+        }
+    }
+
+    async fn db_with_completed_submission(
+        process: &PostgresProcess,
+        committed_in: L1Block,
+    ) -> (Postgres, BlockSubmission) {
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            completed: false,
+            committed_in: None,
+            confirmed: false,
+            attempts: 1,
+            ..rand::thread_rng().gen()
+        };
+        db.insert(submission.clone()).await.unwrap();
+        let submission = db
+            .set_submission_completed(submission.block.hash, committed_in)
+            .await
+            .unwrap();
+
+        (db, submission)
+    }
+}
@@ -4,26 +4,53 @@ use serde::Serialize;
 #[derive(Debug, Serialize)]
 pub struct HealthReport {
     fuel_connection_up: bool,
+    fuel_nodes: Vec<EndpointHealth>,
     eth_connection_healthy: bool,
+    eth_rpc_endpoints: Vec<EndpointHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eth_rpc_quorum_reached: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EndpointHealth {
+    endpoint: String,
+    healthy: bool,
 }
 
 impl HealthReport {
     pub fn healthy(&self) -> bool {
-        self.fuel_connection_up && self.eth_connection_healthy
+        self.fuel_connection_up
+            && self.eth_connection_healthy
+            && self.eth_rpc_quorum_reached.unwrap_or(true)
     }
 }
 
 pub struct HealthReporter {
     fuel_connection: HealthChecker,
+    fuel_nodes: Vec<(String, HealthChecker)>,
     eth_connection: HealthChecker,
+    eth_rpc_endpoints: Vec<(String, HealthChecker)>,
+    eth_rpc_quorum: Option<HealthChecker>,
 }
 
 impl HealthReporter {
+    /// `fuel_nodes` and `eth_rpc_endpoints` are only informative, the fuel connection stays up as
+    /// long as any node is reachable and the eth connection healthy as long as the endpoint in
+    /// use is. `eth_rpc_quorum` is only given if the endpoints must reach a quorum.
     #[must_use]
-    pub fn new(fuel_health_check: HealthChecker, eth_health_check: HealthChecker) -> Self {
+    pub fn new(
+        fuel_health_check: HealthChecker,
+        fuel_nodes: Vec<(String, HealthChecker)>,
+        eth_health_check: HealthChecker,
+        eth_rpc_endpoints: Vec<(String, HealthChecker)>,
+        eth_rpc_quorum: Option<HealthChecker>,
+    ) -> Self {
         Self {
             fuel_connection: fuel_health_check,
+            fuel_nodes,
             eth_connection: eth_health_check,
+            eth_rpc_endpoints,
+            eth_rpc_quorum,
         }
     }
 
@@ -31,7 +58,20 @@ impl HealthReporter {
     pub fn report(&self) -> HealthReport {
         HealthReport {
             fuel_connection_up: self.fuel_connection.healthy(),
+            fuel_nodes: endpoint_health(&self.fuel_nodes),
             eth_connection_healthy: self.eth_connection.healthy(),
+            eth_rpc_endpoints: endpoint_health(&self.eth_rpc_endpoints),
+            eth_rpc_quorum_reached: self.eth_rpc_quorum.as_ref().map(|quorum| quorum.healthy()),
         }
     }
 }
+
+fn endpoint_health(endpoints: &[(String, HealthChecker)]) -> Vec<EndpointHealth> {
+    endpoints
+        .iter()
+        .map(|(endpoint, health_check)| EndpointHealth {
+            endpoint: endpoint.clone(),
+            healthy: health_check.healthy(),
+        })
+        .collect()
+}
@@ -1,54 +1,188 @@
 #![deny(unused_crate_dependencies)]
 mod block_committer;
+mod block_validator;
 mod block_watcher;
 mod commit_listener;
+mod commit_verifier;
+mod consistency_checker;
+mod finality_tracker;
 mod health_reporter;
+mod nonce_manager;
+mod pause_tracker;
 mod status_reporter;
+mod submission_retrier;
+mod transaction_monitor;
 mod wallet_balance_tracker;
 
 pub use block_committer::BlockCommitter;
+pub use block_validator::BlockValidator;
 pub use block_watcher::BlockWatcher;
 pub use commit_listener::CommitListener;
+pub use commit_verifier::CommitVerifier;
+pub use consistency_checker::{ConsistencyChecker, FuelChainConsistency};
+pub use finality_tracker::FinalityTracker;
 pub use health_reporter::HealthReporter;
+pub use pause_tracker::{ContractPause, PauseTracker};
 pub use status_reporter::StatusReporter;
+pub use submission_retrier::SubmissionRetrier;
+pub use transaction_monitor::{GasBumpConfig, TransactionMonitor};
 pub use wallet_balance_tracker::WalletBalanceTracker;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("{0}")]
     Other(String),
-    #[error("Network Error: {0}")]
-    Network(String),
+    #[error("L1 error: {0}")]
+    L1(#[from] ports::l1::Error),
+    #[error("Fuel error: {0}")]
+    Fuel(#[from] ports::fuel::Error),
     #[error("Storage error: {0}")]
-    Storage(String),
+    Storage(#[from] ports::storage::Error),
+    #[error("Rejected fuel block: {0}")]
+    RejectedBlock(String),
+    #[error("Fuel block unavailable: {0}")]
+    MissingFuelBlock(String),
 }
 
-impl From<ports::l1::Error> for Error {
-    fn from(value: ports::l1::Error) -> Self {
-        match value {
-            ports::l1::Error::Network(e) => Self::Network(e),
-            _ => Self::Other(value.to_string()),
+impl Error {
+    /// Whether running again later may get past the error, as opposed to it persisting until
+    /// someone steps in.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::L1(err) => err.is_retryable(),
+            Self::Fuel(err) => err.is_retryable(),
+            Self::Storage(err) => err.is_retryable(),
+            Self::MissingFuelBlock(_) => true,
+            Self::RejectedBlock(_) | Self::Other(_) => false,
         }
     }
-}
 
-impl From<ports::fuel::Error> for Error {
-    fn from(value: ports::fuel::Error) -> Self {
-        match value {
-            ports::fuel::Error::Network(e) => Self::Network(e),
+    /// Names the kind of error, e.g. to label metrics with.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::L1(err) => err.kind(),
+            Self::Fuel(err) => err.kind(),
+            Self::Storage(err) => err.kind(),
+            Self::RejectedBlock(_) => "rejected_block",
+            Self::MissingFuelBlock(_) => "missing_fuel_block",
+            Self::Other(_) => "other",
         }
     }
 }
 
-impl From<ports::storage::Error> for Error {
-    fn from(error: ports::storage::Error) -> Self {
-        Self::Storage(error.to_string())
-    }
-}
-
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[async_trait::async_trait]
 pub trait Runner: Send + Sync {
     async fn run(&mut self) -> Result<()>;
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use ports::{
+        l1::{Contract, EventStreamer, MockApi, MockContract},
+        types::{FuelBlock, FuelBlockCommittedOnL1, L1Height, L1Tx, TxState, U256},
+    };
+
+    pub struct MockL1 {
+        pub api: MockApi,
+        pub contract: MockContract,
+    }
+
+    impl MockL1 {
+        pub fn new() -> Self {
+            Self {
+                api: MockApi::new(),
+                contract: MockContract::new(),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Contract for MockL1 {
+        async fn submit(&self, block: FuelBlock, nonce: u64) -> ports::l1::Result<L1Tx> {
+            self.contract.submit(block, nonce).await
+        }
+        async fn submit_with_blobs(
+            &self,
+            block: FuelBlock,
+            data: Vec<u8>,
+            nonce: u64,
+        ) -> ports::l1::Result<L1Tx> {
+            self.contract.submit_with_blobs(block, data, nonce).await
+        }
+        async fn resubmit(
+            &self,
+            block: FuelBlock,
+            nonce: u64,
+            max_fee_per_gas: u64,
+            max_priority_fee_per_gas: u64,
+        ) -> ports::l1::Result<L1Tx> {
+            self.contract
+                .resubmit(block, nonce, max_fee_per_gas, max_priority_fee_per_gas)
+                .await
+        }
+        async fn resubmit_with_blobs(
+            &self,
+            block: FuelBlock,
+            data: Vec<u8>,
+            nonce: u64,
+            max_fee_per_gas: u64,
+            max_priority_fee_per_gas: u64,
+            max_fee_per_blob_gas: u64,
+        ) -> ports::l1::Result<L1Tx> {
+            self.contract
+                .resubmit_with_blobs(
+                    block,
+                    data,
+                    nonce,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    max_fee_per_blob_gas,
+                )
+                .await
+        }
+        fn event_streamer(&self, height: L1Height) -> Box<dyn EventStreamer + Send + Sync> {
+            self.contract.event_streamer(height)
+        }
+        async fn block_hash_at_commit_height(
+            &self,
+            commit_height: u32,
+        ) -> ports::l1::Result<[u8; 32]> {
+            self.contract
+                .block_hash_at_commit_height(commit_height)
+                .await
+        }
+        async fn commit_event(
+            &self,
+            block: FuelBlock,
+            from_height: L1Height,
+        ) -> ports::l1::Result<Option<FuelBlockCommittedOnL1>> {
+            self.contract.commit_event(block, from_height).await
+        }
+        async fn finalized(&self, block: FuelBlock) -> ports::l1::Result<bool> {
+            self.contract.finalized(block).await
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ports::l1::Api for MockL1 {
+        async fn get_block_number(&self) -> ports::l1::Result<L1Height> {
+            self.api.get_block_number().await
+        }
+        async fn balance(&self) -> ports::l1::Result<U256> {
+            self.api.balance().await
+        }
+        async fn pending_nonce(&self) -> ports::l1::Result<u64> {
+            self.api.pending_nonce().await
+        }
+        async fn tx_state(&self, tx_hash: [u8; 32]) -> ports::l1::Result<TxState> {
+            self.api.tx_state(tx_hash).await
+        }
+        async fn block_hash_at(&self, height: L1Height) -> ports::l1::Result<Option<[u8; 32]>> {
+            self.api.block_hash_at(height).await
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use ports::storage::Storage;
+use tracing::warn;
+
+use crate::Result;
+
+/// Hands out the nonces of commit txs, so that a commit can be sent while earlier ones are still
+/// pending. The last used nonce is persisted so that a restart doesn't reuse it.
+#[derive(Debug, Default)]
+pub(crate) struct NonceManager {
+    // `None` until reconciled with the chain
+    next: Option<u64>,
+}
+
+impl NonceManager {
+    /// The nonce the next commit tx should be sent with.
+    pub(crate) async fn next(
+        &mut self,
+        l1: &impl ports::l1::Api,
+        storage: &impl Storage,
+    ) -> Result<u64> {
+        if let Some(nonce) = self.next {
+            return Ok(nonce);
+        }
+
+        let nonce = Self::reconcile(l1, storage).await?;
+        self.next = Some(nonce);
+
+        Ok(nonce)
+    }
+
+    /// Records `nonce` as taken by a sent tx.
+    pub(crate) async fn used(&mut self, nonce: u64, storage: &impl Storage) -> Result<()> {
+        storage.set_last_used_nonce(nonce).await?;
+        self.next = Some(nonce + 1);
+
+        Ok(())
+    }
+
+    /// Forgets the locally tracked nonce, having it reconciled with the chain when next asked for.
+    pub(crate) fn reset(&mut self) {
+        self.next = None;
+    }
+
+    async fn reconcile(l1: &impl ports::l1::Api, storage: &impl Storage) -> Result<u64> {
+        let pending_nonce = l1.pending_nonce().await?;
+        let Some(last_used) = storage.last_used_nonce().await? else {
+            return Ok(pending_nonce);
+        };
+
+        if last_used < pending_nonce {
+            return Ok(pending_nonce);
+        }
+
+        // The node doesn't know about some of the txs we sent. Skipping past them is only safe
+        // while they are still tracked as pending, a gap in the nonces would hold back every
+        // later tx.
+        let in_flight = storage
+            .pending_txs()
+            .await?
+            .iter()
+            .any(|tx| tx.tx.nonce >= pending_nonce);
+
+        if in_flight {
+            Ok(last_used + 1)
+        } else {
+            warn!("txs up to nonce {last_used} got lost, reusing nonces from {pending_nonce}");
+            Ok(pending_nonce)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ports::{
+        l1::MockApi,
+        types::{SubmissionTx, TxState},
+    };
+    use rand::Rng;
+    use storage::PostgresProcess;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn resumes_after_the_persisted_nonce() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        db.set_last_used_nonce(4).await.unwrap();
+
+        let l1 = given_l1_w_pending_nonce(7);
+
+        // when
+        let nonce = NonceManager::default().next(&l1, &db).await.unwrap();
+
+        // then
+        assert_eq!(nonce, 7);
+    }
+
+    #[tokio::test]
+    async fn skips_nonces_of_txs_the_node_does_not_know_about() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        given_pending_tx_w_nonce(&db, 5).await;
+        db.set_last_used_nonce(5).await.unwrap();
+
+        let l1 = given_l1_w_pending_nonce(5);
+
+        // when
+        let nonce = NonceManager::default().next(&l1, &db).await.unwrap();
+
+        // then
+        assert_eq!(nonce, 6);
+    }
+
+    #[tokio::test]
+    async fn reuses_nonces_of_lost_txs() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        db.set_last_used_nonce(5).await.unwrap();
+
+        let l1 = given_l1_w_pending_nonce(3);
+
+        // when
+        let nonce = NonceManager::default().next(&l1, &db).await.unwrap();
+
+        // then
+        assert_eq!(nonce, 3);
+    }
+
+    #[tokio::test]
+    async fn hands_out_consecutive_nonces() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut l1 = MockApi::new();
+        l1.expect_pending_nonce().times(1).returning(|| Ok(2));
+
+        let mut nonce_manager = NonceManager::default();
+        let first = nonce_manager.next(&l1, &db).await.unwrap();
+
+        // when
+        nonce_manager.used(first, &db).await.unwrap();
+        let second = nonce_manager.next(&l1, &db).await.unwrap();
+
+        // then
+        assert_eq!((first, second), (2, 3));
+        assert_eq!(db.last_used_nonce().await.unwrap(), Some(2));
+    }
+
+    fn given_l1_w_pending_nonce(nonce: u64) -> MockApi {
+        let mut l1 = MockApi::new();
+        l1.expect_pending_nonce().returning(move || Ok(nonce));
+        l1
+    }
+
+    async fn given_pending_tx_w_nonce(db: &impl Storage, nonce: u64) {
+        let mut rng = rand::thread_rng();
+        let submission = rng.gen::<ports::types::BlockSubmission>();
+        db.insert(submission.clone()).await.unwrap();
+
+        let mut tx = rng.gen::<ports::types::L1Tx>();
+        tx.nonce = nonce;
+        db.insert_tx(SubmissionTx {
+            fuel_block_hash: submission.block.hash,
+            tx,
+            state: TxState::Pending,
+            submittal_height: 0u32.into(),
+        })
+        .await
+        .unwrap();
+    }
+}
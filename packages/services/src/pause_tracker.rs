@@ -0,0 +1,162 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntGauge, Opts},
+    RegistersMetrics,
+};
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+use super::Runner;
+use crate::Result;
+
+/// Shared flag telling whether the state contract is paused, rejecting any commit.
+#[derive(Debug, Clone, Default)]
+pub struct ContractPause {
+    paused: Arc<AtomicBool>,
+    unpaused: Arc<Notify>,
+}
+
+impl ContractPause {
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        let was_paused = self.paused.swap(paused, Ordering::Relaxed);
+        if was_paused && !paused {
+            self.unpaused.notify_waiters();
+        }
+    }
+
+    /// Resolves once the contract isn't paused.
+    pub(crate) async fn unpaused(&self) {
+        loop {
+            // created before checking the flag so that an unpause in between isn't missed
+            let notified = self.unpaused.notified();
+            if !self.paused() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Polls whether the state contract is paused, sharing the outcome through [`ContractPause`].
+pub struct PauseTracker<S> {
+    contract_state: S,
+    pause: ContractPause,
+    metrics: Metrics,
+}
+
+impl<S> PauseTracker<S> {
+    pub fn new(contract_state: S) -> Self {
+        Self {
+            contract_state,
+            pause: ContractPause::default(),
+            metrics: Metrics::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn pause(&self) -> ContractPause {
+        self.pause.clone()
+    }
+}
+
+#[async_trait]
+impl<S> Runner for PauseTracker<S>
+where
+    S: ports::l1::ContractState,
+{
+    async fn run(&mut self) -> Result<()> {
+        let paused = self.contract_state.paused().await?;
+
+        match (self.pause.paused(), paused) {
+            (false, true) => warn!("state contract got paused, holding back commits"),
+            (true, false) => info!("state contract got unpaused, resuming commits"),
+            _ => {}
+        }
+
+        self.pause.set_paused(paused);
+        self.metrics.state_contract_paused.set(i64::from(paused));
+
+        Ok(())
+    }
+}
+
+impl<S> RegistersMetrics for PauseTracker<S> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![Box::new(self.metrics.state_contract_paused.clone())]
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    state_contract_paused: IntGauge,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let state_contract_paused = IntGauge::with_opts(Opts::new(
+            "state_contract_paused",
+            "Whether the state contract is paused (1) or accepting commits (0).",
+        ))
+        .expect("state_contract_paused metric to be correctly configured");
+
+        Self {
+            state_contract_paused,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ports::l1::MockContractState;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn shares_the_paused_state() {
+        // given
+        let mut contract_state = MockContractState::new();
+        contract_state.expect_paused().returning(|| Ok(true));
+
+        let mut tracker = PauseTracker::new(contract_state);
+        let pause = tracker.pause();
+
+        // when
+        tracker.run().await.unwrap();
+
+        // then
+        assert!(pause.paused());
+    }
+
+    #[tokio::test]
+    async fn unpausing_wakes_up_the_waiters() {
+        // given
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+
+        let waiter = tokio::spawn({
+            let pause = pause.clone();
+            async move { pause.unpaused().await }
+        });
+
+        // when
+        pause.set_paused(false);
+
+        // then
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter to be woken up")
+            .unwrap();
+    }
+}
@@ -1,11 +1,27 @@
 use ports::storage::Storage;
 use serde::Serialize;
 
-use crate::Result;
+use crate::{ContractPause, FuelChainConsistency, Result};
 
 #[derive(Debug, Serialize, Default, PartialEq, Eq)]
 pub struct StatusReport {
     pub status: Status,
+    /// Set when the fuel node reports a different block than the one we submitted.
+    pub fuel_block_hash_mismatch: bool,
+    /// Set while the state contract is paused, blocks are held back until it is unpaused.
+    pub state_contract_paused: bool,
+}
+
+/// A submission along with what the state contract holds for it.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct SubmissionReport {
+    pub fuel_block_hash: String,
+    pub fuel_block_height: u32,
+    pub completed: bool,
+    /// Whether the state contract holds the submitted hash, `None` until verified.
+    pub committed_hash_matches: Option<bool>,
+    /// Whether the state contract considers the commit finalized, `None` until verified.
+    pub finalized: Option<bool>,
 }
 
 #[derive(Serialize, Debug, Default, PartialEq, Eq)]
@@ -17,11 +33,21 @@ pub enum Status {
 
 pub struct StatusReporter<Db> {
     storage: Db,
+    fuel_chain_consistency: FuelChainConsistency,
+    contract_pause: ContractPause,
 }
 
 impl<Db> StatusReporter<Db> {
-    pub fn new(storage: Db) -> Self {
-        Self { storage }
+    pub fn new(
+        storage: Db,
+        fuel_chain_consistency: FuelChainConsistency,
+        contract_pause: ContractPause,
+    ) -> Self {
+        Self {
+            storage,
+            fuel_chain_consistency,
+            contract_pause,
+        }
     }
 }
 impl<Db> StatusReporter<Db>
@@ -41,7 +67,33 @@ where
             Status::Idle
         };
 
-        Ok(StatusReport { status })
+        Ok(StatusReport {
+            status,
+            fuel_block_hash_mismatch: self.fuel_chain_consistency.mismatch_detected(),
+            state_contract_paused: self.contract_pause.paused(),
+        })
+    }
+
+    /// Up to `limit` of the most recent submissions, highest fuel block first.
+    pub async fn latest_submissions(&self, limit: u32) -> Result<Vec<SubmissionReport>> {
+        let mut reports = vec![];
+
+        for submission in self.storage.latest_submissions(limit).await? {
+            let verification = self
+                .storage
+                .commit_verification(submission.block.hash)
+                .await?;
+
+            reports.push(SubmissionReport {
+                fuel_block_hash: hex::encode(submission.block.hash),
+                fuel_block_height: submission.block.height,
+                completed: submission.completed,
+                committed_hash_matches: verification.map(|verification| verification.matches()),
+                finalized: verification.map(|verification| verification.finalized),
+            });
+        }
+
+        Ok(reports)
     }
 }
 
@@ -49,7 +101,7 @@ where
 mod tests {
     use std::sync::Arc;
 
-    use ports::types::BlockSubmission;
+    use ports::types::{BlockSubmission, CommitVerification};
     use rand::Rng;
     use storage::PostgresProcess;
 
@@ -73,7 +125,11 @@ mod tests {
                     db.insert(latest_submission).await.unwrap();
                 }
 
-                let status_reporter = StatusReporter::new(db);
+                let status_reporter = StatusReporter::new(
+                    db,
+                    FuelChainConsistency::default(),
+                    ContractPause::default(),
+                );
 
                 // when
                 let status = status_reporter.current_status().await.unwrap();
@@ -82,7 +138,9 @@ mod tests {
                 assert_eq!(
                     status,
                     StatusReport {
-                        status: expected_app_status
+                        status: expected_app_status,
+                        fuel_block_hash_mismatch: false,
+                        state_contract_paused: false,
                     }
                 );
             }
@@ -95,4 +153,93 @@ mod tests {
         // has no entry
         test(None, Status::Idle).await;
     }
+
+    #[tokio::test]
+    async fn reports_verification_of_latest_submissions() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let mut rng = rand::thread_rng();
+        let mut verified: BlockSubmission = rng.gen();
+        verified.block.height = 2;
+        let mut unverified: BlockSubmission = rng.gen();
+        unverified.block.height = 1;
+        for submission in [&verified, &unverified] {
+            db.insert(submission.clone()).await.unwrap();
+        }
+        db.set_commit_verification(CommitVerification {
+            fuel_block_hash: verified.block.hash,
+            committed_hash: Some(verified.block.hash),
+            finalized: true,
+        })
+        .await
+        .unwrap();
+
+        let status_reporter = StatusReporter::new(
+            db,
+            FuelChainConsistency::default(),
+            ContractPause::default(),
+        );
+
+        // when
+        let reports = status_reporter.latest_submissions(10).await.unwrap();
+
+        // then
+        assert_eq!(
+            reports,
+            vec![
+                SubmissionReport {
+                    fuel_block_hash: hex::encode(verified.block.hash),
+                    fuel_block_height: 2,
+                    completed: verified.completed,
+                    committed_hash_matches: Some(true),
+                    finalized: Some(true),
+                },
+                SubmissionReport {
+                    fuel_block_hash: hex::encode(unverified.block.hash),
+                    fuel_block_height: 1,
+                    completed: unverified.completed,
+                    committed_hash_matches: None,
+                    finalized: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn status_reports_paused_state_contract() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+
+        let status_reporter = StatusReporter::new(db, FuelChainConsistency::default(), pause);
+
+        // when
+        let status = status_reporter.current_status().await.unwrap();
+
+        // then
+        assert!(status.state_contract_paused);
+    }
+
+    #[tokio::test]
+    async fn status_reports_fuel_block_hash_mismatch() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let consistency = FuelChainConsistency::default();
+        consistency.set_mismatch_detected(true);
+
+        let status_reporter = StatusReporter::new(db, consistency, ContractPause::default());
+
+        // when
+        let status = status_reporter.current_status().await.unwrap();
+
+        // then
+        assert!(status.fuel_block_hash_mismatch);
+    }
 }
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntCounter, IntGauge, Opts},
+    RegistersMetrics,
+};
+use ports::{
+    storage::Storage,
+    types::{FuelBlock, L1Height},
+};
+use tokio::sync::mpsc::Sender;
+use tracing::{info, warn};
+
+use super::Runner;
+use crate::{ContractPause, Error, Result};
+
+/// Re-enqueues submissions that stayed incomplete for too long without a pending or mined tx, e.g.
+/// because sending the tx failed or it got reverted or dropped.
+pub struct SubmissionRetrier<A, Db> {
+    l1: A,
+    storage: Db,
+    tx_fuel_block: Sender<FuelBlock>,
+    retry_after_blocks: u32,
+    max_attempts: u32,
+    pause: ContractPause,
+    metrics: Metrics,
+}
+
+impl<A, Db> SubmissionRetrier<A, Db> {
+    pub fn new(
+        l1: A,
+        storage: Db,
+        tx_fuel_block: Sender<FuelBlock>,
+        retry_after_blocks: u32,
+        max_attempts: u32,
+        pause: ContractPause,
+    ) -> Self {
+        Self {
+            l1,
+            storage,
+            tx_fuel_block,
+            retry_after_blocks,
+            max_attempts,
+            pause,
+            metrics: Metrics::default(),
+        }
+    }
+}
+
+impl<A, Db> SubmissionRetrier<A, Db>
+where
+    A: ports::l1::Api,
+    Db: Storage,
+{
+    async fn retry_stale_submissions(&self) -> Result<()> {
+        let current_height = self.l1.get_block_number().await?;
+        let Some(cutoff) = u64::from(current_height).checked_sub(self.retry_after_blocks.into())
+        else {
+            return Ok(());
+        };
+        let cutoff = L1Height::try_from(cutoff).map_err(|e| Error::Other(e.to_string()))?;
+
+        let stale_submissions = self.storage.stale_submissions(cutoff).await?;
+        self.metrics
+            .stale_submissions
+            .set(stale_submissions.len().try_into().unwrap_or(i64::MAX));
+
+        for submission in stale_submissions {
+            if submission.attempts >= self.max_attempts {
+                warn!(
+                    "giving up on {:?} after {} attempts",
+                    submission.block, submission.attempts
+                );
+                continue;
+            }
+
+            let submission = self
+                .storage
+                .record_submission_attempt(submission.block.hash, current_height)
+                .await?;
+
+            self.tx_fuel_block
+                .send(submission.block)
+                .await
+                .map_err(|e| Error::Other(e.to_string()))?;
+
+            info!(
+                "re-enqueued {:?}, attempt {}",
+                submission.block, submission.attempts
+            );
+            self.metrics.submission_retries.inc();
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<A, Db> Runner for SubmissionRetrier<A, Db>
+where
+    A: ports::l1::Api + Send + Sync,
+    Db: Storage,
+{
+    async fn run(&mut self) -> Result<()> {
+        // retried commits would only revert, and get counted as attempts
+        if self.pause.paused() {
+            return Ok(());
+        }
+
+        self.retry_stale_submissions().await
+    }
+}
+
+impl<A, Db> RegistersMetrics for SubmissionRetrier<A, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        self.metrics.metrics()
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    stale_submissions: IntGauge,
+    submission_retries: IntCounter,
+}
+
+impl RegistersMetrics for Metrics {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![
+            Box::new(self.stale_submissions.clone()),
+            Box::new(self.submission_retries.clone()),
+        ]
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let stale_submissions = IntGauge::with_opts(Opts::new(
+            "stale_submissions",
+            "Number of incomplete block submissions without a pending commit transaction.",
+        ))
+        .expect("stale_submissions metric to be correctly configured");
+
+        let submission_retries = IntCounter::with_opts(Opts::new(
+            "submission_retries",
+            "Number of times a stale block submission was sent for committing again.",
+        ))
+        .expect("submission_retries metric to be correctly configured");
+
+        Self {
+            stale_submissions,
+            submission_retries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ports::{l1, types::BlockSubmission};
+    use rand::Rng;
+    use storage::{Postgres, PostgresProcess};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn re_enqueues_stale_submission() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let stale = given_submission(10, 1);
+        let db = db_with_submissions(&process, vec![stale.clone()]).await;
+
+        let mut retrier = SubmissionRetrier::new(
+            given_l1_at_height(15),
+            db.clone(),
+            tx,
+            5,
+            3,
+            ContractPause::default(),
+        );
+
+        // when
+        retrier.run().await.unwrap();
+
+        // then
+        assert_eq!(rx.try_recv().unwrap(), stale.block);
+        assert_eq!(
+            db.submission(stale.block.hash).await.unwrap(),
+            Some(BlockSubmission {
+                attempts: 2,
+                submittal_height: 15u32.into(),
+                ..stale
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn wont_re_enqueue_submission_within_the_window() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![given_submission(11, 1)]).await;
+
+        let mut retrier = SubmissionRetrier::new(
+            given_l1_at_height(15),
+            db,
+            tx,
+            5,
+            3,
+            ContractPause::default(),
+        );
+
+        // when
+        retrier.run().await.unwrap();
+
+        // then
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = db_with_submissions(&process, vec![given_submission(10, 3)]).await;
+
+        let mut retrier = SubmissionRetrier::new(
+            given_l1_at_height(15),
+            db,
+            tx,
+            5,
+            3,
+            ContractPause::default(),
+        );
+
+        // when
+        retrier.run().await.unwrap();
+
+        // then
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn wont_re_enqueue_submission_while_contract_is_paused() {
+        // given
+        let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+        let process = PostgresProcess::shared().await.unwrap();
+        let stale = given_submission(10, 1);
+        let db = db_with_submissions(&process, vec![stale.clone()]).await;
+
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+        let mut retrier =
+            SubmissionRetrier::new(given_l1_at_height(15), db.clone(), tx, 5, 3, pause);
+
+        // when
+        retrier.run().await.unwrap();
+
+        // then
+        assert!(rx.try_recv().is_err());
+        assert_eq!(db.submission(stale.block.hash).await.unwrap(), Some(stale));
+    }
+
+    fn given_l1_at_height(height: u32) -> l1::MockApi {
+        let mut l1 = l1::MockApi::new();
+        l1.expect_get_block_number()
+            .returning(move || Ok(height.into()));
+        l1
+    }
+
+    fn given_submission(submittal_height: u32, attempts: u32) -> BlockSubmission {
+        BlockSubmission {
+            completed: false,
+            submittal_height: submittal_height.into(),
+            attempts,
+            ..rand::thread_rng().gen()
+        }
+    }
+
+    async fn db_with_submissions(
+        process: &PostgresProcess,
+        submissions: Vec<BlockSubmission>,
+    ) -> Postgres {
+        let db = process.create_random_db().await.unwrap();
+        for submission in submissions {
+            db.insert(submission).await.unwrap();
+        }
+
+        db
+    }
+}
@@ -0,0 +1,722 @@
+use async_trait::async_trait;
+use metrics::{
+    prometheus::{core::Collector, IntCounter, IntGauge, Opts},
+    RegistersMetrics,
+};
+use ports::{
+    storage::Storage,
+    types::{FuelBlock, L1Height, L1Tx, SubmissionTx, TxState},
+};
+use tracing::{info, warn};
+
+use super::Runner;
+use crate::{ContractPause, Error, Result};
+
+// Nodes reject replacements that don't raise both fees by at least this much.
+const MIN_REPLACEMENT_FEE_INCREASE_PERCENTAGE: u64 = 10;
+// Blob transactions are only replaced by ones doubling every fee, the blob fee included.
+const MIN_BLOB_REPLACEMENT_FEE_INCREASE_PERCENTAGE: u64 = 100;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GasBumpConfig {
+    /// Number of L1 blocks a transaction may stay pending before it is replaced.
+    pub replace_after_blocks: u32,
+    /// By how much both EIP-1559 fees are raised with every replacement.
+    pub fee_increase_percentage: u64,
+    /// Fees are never raised above this value, in wei.
+    pub max_fee_per_gas: u64,
+}
+
+pub struct TransactionMonitor<L1, Db> {
+    l1: L1,
+    storage: Db,
+    gas_bump: GasBumpConfig,
+    pause: ContractPause,
+    // replacements of blob txs have to carry the block data again
+    blob_source: Option<Box<dyn ports::fuel::Api>>,
+    metrics: Metrics,
+}
+
+impl<L1, Db> TransactionMonitor<L1, Db> {
+    pub fn new(l1: L1, storage: Db, gas_bump: GasBumpConfig, pause: ContractPause) -> Self {
+        Self {
+            l1,
+            storage,
+            gas_bump,
+            pause,
+            blob_source: None,
+            metrics: Metrics::default(),
+        }
+    }
+
+    /// Fetches the data of blocks whose commit tx carried blobs from `fuel_adapter`, so that
+    /// stuck blob txs can be replaced.
+    #[must_use]
+    pub fn with_blobs(mut self, fuel_adapter: impl ports::fuel::Api + 'static) -> Self {
+        self.blob_source = Some(Box::new(fuel_adapter));
+        self
+    }
+
+    fn is_stuck(&self, tx: &SubmissionTx, current_height: L1Height) -> bool {
+        let pending_for = u64::from(current_height).saturating_sub(tx.submittal_height.into());
+        pending_for >= u64::from(self.gas_bump.replace_after_blocks)
+    }
+
+    /// The max fee, max priority fee and, for blob txs, the max blob fee of the replacement.
+    fn bumped_fees(&self, tx: &L1Tx) -> Option<(u64, u64, Option<u64>)> {
+        let increase = |fee: u64, percentage: u64| {
+            let increased = (u128::from(fee) * u128::from(100 + percentage)).div_ceil(100);
+            u64::try_from(increased).unwrap_or(u64::MAX)
+        };
+
+        let min_increase = if tx.max_fee_per_blob_gas.is_some() {
+            MIN_BLOB_REPLACEMENT_FEE_INCREASE_PERCENTAGE
+        } else {
+            MIN_REPLACEMENT_FEE_INCREASE_PERCENTAGE
+        };
+        let percentage = self.gas_bump.fee_increase_percentage.max(min_increase);
+
+        let max_fee_per_gas =
+            increase(tx.max_fee_per_gas, percentage).min(self.gas_bump.max_fee_per_gas);
+        let max_priority_fee_per_gas =
+            increase(tx.max_priority_fee_per_gas, percentage).min(max_fee_per_gas);
+        let max_fee_per_blob_gas = tx.max_fee_per_blob_gas.map(|fee| increase(fee, percentage));
+
+        let acceptable = max_fee_per_gas >= increase(tx.max_fee_per_gas, min_increase)
+            && max_priority_fee_per_gas >= increase(tx.max_priority_fee_per_gas, min_increase);
+
+        acceptable.then_some((
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas,
+        ))
+    }
+}
+
+impl<L1, Db> TransactionMonitor<L1, Db>
+where
+    L1: ports::l1::Contract + ports::l1::Api,
+    Db: Storage,
+{
+    async fn check_pending_txs(&self) -> Result<()> {
+        let unsettled_txs = self.storage.unsettled_txs().await?;
+        let pending = unsettled_txs
+            .iter()
+            .filter(|tx| tx.state == TxState::Pending)
+            .count();
+        self.metrics
+            .pending_l1_txs
+            .set(pending.try_into().unwrap_or(i64::MAX));
+
+        if unsettled_txs.is_empty() {
+            return Ok(());
+        }
+
+        let current_height = self.l1.get_block_number().await?;
+
+        for same_nonce in unsettled_txs.chunk_by(|a, b| a.tx.nonce == b.tx.nonce) {
+            self.check_same_nonce(same_nonce, current_height).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the pending tx of a nonce along with the txs it replaced, as any of them may be the
+    /// one that gets mined.
+    async fn check_same_nonce(&self, txs: &[SubmissionTx], current_height: L1Height) -> Result<()> {
+        let mut states = Vec::with_capacity(txs.len());
+        for tx in txs {
+            states.push((*tx, self.l1.tx_state(tx.tx.hash).await?));
+        }
+
+        // only one tx of a nonce makes it into a block, superseding all the others
+        if let Some((included, state)) = states
+            .iter()
+            .find(|(_, state)| matches!(state, TxState::Mined | TxState::Reverted))
+        {
+            self.settle(included, *state).await?;
+            for (tx, _) in &states {
+                if tx.state == TxState::Pending && tx.tx.hash != included.tx.hash {
+                    self.settle(tx, TxState::Replaced).await?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        // a tx that got replaced might still be mined, the nonce isn't lost until it's gone too
+        let nonce_in_mempool = states.iter().any(|(_, state)| *state == TxState::Pending);
+
+        for (tx, state) in states {
+            if tx.state != TxState::Pending {
+                continue;
+            }
+
+            match state {
+                TxState::Pending => {
+                    // a replacement would only revert, the tx is bumped once the contract is
+                    // unpaused
+                    if self.is_stuck(&tx, current_height) && !self.pause.paused() {
+                        self.replace(tx, current_height).await?;
+                    }
+                }
+                TxState::Dropped if nonce_in_mempool => {}
+                state => self.settle(&tx, state).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn replace(&self, tx: SubmissionTx, current_height: L1Height) -> Result<()> {
+        let Some((max_fee_per_gas, max_priority_fee_per_gas, max_fee_per_blob_gas)) =
+            self.bumped_fees(&tx.tx)
+        else {
+            warn!(
+                "{:?} is stuck but its fees cannot be raised past the cap of {} wei",
+                tx.tx, self.gas_bump.max_fee_per_gas
+            );
+            return Ok(());
+        };
+
+        let submission = self
+            .storage
+            .submission(tx.fuel_block_hash)
+            .await?
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "no submission found for the block committed by {:?}",
+                    tx.tx
+                ))
+            })?;
+
+        let replacement = match max_fee_per_blob_gas {
+            Some(max_fee_per_blob_gas) => {
+                let data = self.blob_data(&tx, submission.block).await?;
+                self.l1
+                    .resubmit_with_blobs(
+                        submission.block,
+                        data,
+                        tx.tx.nonce,
+                        max_fee_per_gas,
+                        max_priority_fee_per_gas,
+                        max_fee_per_blob_gas,
+                    )
+                    .await?
+            }
+            None => {
+                self.l1
+                    .resubmit(
+                        submission.block,
+                        tx.tx.nonce,
+                        max_fee_per_gas,
+                        max_priority_fee_per_gas,
+                    )
+                    .await?
+            }
+        };
+
+        self.storage
+            .record_replacement(
+                tx.tx.hash,
+                SubmissionTx {
+                    fuel_block_hash: tx.fuel_block_hash,
+                    tx: replacement,
+                    state: TxState::Pending,
+                    submittal_height: current_height,
+                },
+            )
+            .await?;
+
+        info!("{:?} replaced by {replacement:?}", tx.tx);
+        self.metrics.replaced_l1_txs.inc();
+
+        Ok(())
+    }
+
+    async fn blob_data(&self, tx: &SubmissionTx, block: FuelBlock) -> Result<Vec<u8>> {
+        let fuel_adapter = self.blob_source.as_ref().ok_or_else(|| {
+            Error::Other(format!(
+                "{:?} carries blobs but there is no source for the data of {block:?}",
+                tx.tx
+            ))
+        })?;
+
+        let data = fuel_adapter
+            .block_data(block.height)
+            .await?
+            .ok_or_else(|| {
+                Error::MissingFuelBlock(format!("{block:?} not found on the fuel chain"))
+            })?;
+
+        Ok(data)
+    }
+
+    async fn settle(&self, tx: &SubmissionTx, state: TxState) -> Result<()> {
+        self.storage.update_tx_state(tx.tx.hash, state).await?;
+
+        match state {
+            TxState::Mined => {
+                info!("{:?} mined", tx.tx);
+            }
+            TxState::Reverted => {
+                warn!("{:?} reverted", tx.tx);
+                self.metrics.reverted_l1_txs.inc();
+            }
+            TxState::Dropped => {
+                warn!("{:?} dropped from the mempool", tx.tx);
+                self.metrics.dropped_l1_txs.inc();
+            }
+            TxState::Replaced => {
+                info!("{:?} superseded by a tx of the same nonce", tx.tx);
+            }
+            TxState::Pending => {}
+        }
+
+        if tx.state == TxState::Pending {
+            self.metrics.pending_l1_txs.dec();
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<L1, Db> Runner for TransactionMonitor<L1, Db>
+where
+    L1: ports::l1::Contract + ports::l1::Api,
+    Db: Storage,
+{
+    async fn run(&mut self) -> Result<()> {
+        self.check_pending_txs().await
+    }
+}
+
+impl<L1, Db> RegistersMetrics for TransactionMonitor<L1, Db> {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        self.metrics.metrics()
+    }
+}
+
+#[derive(Clone)]
+struct Metrics {
+    pending_l1_txs: IntGauge,
+    reverted_l1_txs: IntCounter,
+    dropped_l1_txs: IntCounter,
+    replaced_l1_txs: IntCounter,
+}
+
+impl RegistersMetrics for Metrics {
+    fn metrics(&self) -> Vec<Box<dyn Collector>> {
+        vec![
+            Box::new(self.pending_l1_txs.clone()),
+            Box::new(self.reverted_l1_txs.clone()),
+            Box::new(self.dropped_l1_txs.clone()),
+            Box::new(self.replaced_l1_txs.clone()),
+        ]
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let pending_l1_txs = IntGauge::with_opts(Opts::new(
+            "pending_l1_txs",
+            "Number of commit transactions waiting to be included on Ethereum.",
+        ))
+        .expect("pending_l1_txs metric to be correctly configured");
+
+        let reverted_l1_txs = IntCounter::with_opts(Opts::new(
+            "reverted_l1_txs",
+            "Number of commit transactions that were reverted on Ethereum.",
+        ))
+        .expect("reverted_l1_txs metric to be correctly configured");
+
+        let dropped_l1_txs = IntCounter::with_opts(Opts::new(
+            "dropped_l1_txs",
+            "Number of commit transactions that were dropped from the mempool.",
+        ))
+        .expect("dropped_l1_txs metric to be correctly configured");
+
+        let replaced_l1_txs = IntCounter::with_opts(Opts::new(
+            "replaced_l1_txs",
+            "Number of stuck commit transactions replaced by ones paying higher fees.",
+        ))
+        .expect("replaced_l1_txs metric to be correctly configured");
+
+        Self {
+            pending_l1_txs,
+            reverted_l1_txs,
+            dropped_l1_txs,
+            replaced_l1_txs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics::prometheus::{proto::Metric, Registry};
+    use mockall::predicate::eq;
+    use ports::types::{BlockSubmission, SubmissionTx};
+    use rand::Rng;
+    use storage::{Postgres, PostgresProcess};
+
+    use super::*;
+    use crate::test_utils::MockL1;
+
+    const GAS_BUMP: GasBumpConfig = GasBumpConfig {
+        replace_after_blocks: 5,
+        fee_increase_percentage: 20,
+        max_fee_per_gas: 1_000,
+    };
+
+    #[tokio::test]
+    async fn updates_state_of_settled_txs() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let mined_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+        let still_pending_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+
+        let mut l1 = given_l1_at_height(0);
+        given_tx_state(&mut l1, mined_tx, TxState::Mined);
+        given_tx_state(&mut l1, still_pending_tx, TxState::Pending);
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        let pending = db.pending_txs().await.unwrap();
+        assert_eq!(pending, vec![still_pending_tx]);
+    }
+
+    #[tokio::test]
+    async fn reverted_and_dropped_txs_seen_in_metrics() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let reverted_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+        let dropped_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+
+        let mut l1 = given_l1_at_height(0);
+        given_tx_state(&mut l1, reverted_tx, TxState::Reverted);
+        given_tx_state(&mut l1, dropped_tx, TxState::Dropped);
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+        let registry = Registry::new();
+        monitor.register_metrics(&registry);
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        let metrics = registry.gather();
+        let counter = |name: &str| {
+            metrics
+                .iter()
+                .find(|metric| metric.get_name() == name)
+                .and_then(|metric| metric.get_metric().first())
+                .map(Metric::get_counter)
+                .unwrap()
+                .get_value()
+        };
+
+        assert_eq!(counter("reverted_l1_txs"), 1f64);
+        assert_eq!(counter("dropped_l1_txs"), 1f64);
+        assert!(db.pending_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn replaces_stuck_tx_with_bumped_fees() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let stuck_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+        let block = db
+            .submission(stuck_tx.fuel_block_hash)
+            .await
+            .unwrap()
+            .unwrap()
+            .block;
+
+        let current_height = u64::from(stuck_tx.submittal_height) + 5;
+        let mut l1 = given_l1_at_height(current_height);
+        given_tx_state(&mut l1, stuck_tx, TxState::Pending);
+
+        let replacement = L1Tx {
+            nonce: stuck_tx.tx.nonce,
+            ..given_l1_tx(120, 12)
+        };
+        l1.contract
+            .expect_resubmit()
+            .with(eq(block), eq(stuck_tx.tx.nonce), eq(120), eq(12))
+            .return_once(move |_, _, _, _| Ok(replacement));
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        let pending = db.pending_txs().await.unwrap();
+        assert_eq!(
+            pending,
+            vec![SubmissionTx {
+                fuel_block_hash: stuck_tx.fuel_block_hash,
+                tx: replacement,
+                state: TxState::Pending,
+                submittal_height: current_height.try_into().unwrap(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_stuck_blob_tx_with_a_blob_tx() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let stuck_tx = given_pending_tx_in_db(&db, given_blob_tx(100, 10, 7)).await;
+        let block = db
+            .submission(stuck_tx.fuel_block_hash)
+            .await
+            .unwrap()
+            .unwrap()
+            .block;
+
+        let current_height = u64::from(stuck_tx.submittal_height) + 5;
+        let mut l1 = given_l1_at_height(current_height);
+        given_tx_state(&mut l1, stuck_tx, TxState::Pending);
+
+        let replacement = L1Tx {
+            nonce: stuck_tx.tx.nonce,
+            ..given_blob_tx(200, 20, 14)
+        };
+        l1.contract
+            .expect_resubmit_with_blobs()
+            .with(
+                eq(block),
+                eq(vec![1, 2, 3]),
+                eq(stuck_tx.tx.nonce),
+                eq(200),
+                eq(20),
+                eq(14),
+            )
+            .return_once(move |_, _, _, _, _, _| Ok(replacement));
+
+        let mut fuel_adapter = ports::fuel::MockApi::new();
+        fuel_adapter
+            .expect_block_data()
+            .with(eq(block.height))
+            .return_once(|_| Ok(Some(vec![1, 2, 3])));
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default())
+                .with_blobs(fuel_adapter);
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        let pending = db.pending_txs().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].tx, replacement);
+    }
+
+    #[tokio::test]
+    async fn wont_replace_tx_before_it_is_considered_stuck() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let pending_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+
+        let current_height = u64::from(pending_tx.submittal_height) + 4;
+        let mut l1 = given_l1_at_height(current_height);
+        given_tx_state(&mut l1, pending_tx, TxState::Pending);
+        l1.contract.expect_resubmit().never();
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap(), vec![pending_tx]);
+    }
+
+    #[tokio::test]
+    async fn wont_replace_stuck_tx_while_contract_is_paused() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let stuck_tx = given_pending_tx_in_db(&db, given_l1_tx(100, 10)).await;
+
+        let current_height = u64::from(stuck_tx.submittal_height) + 5;
+        let mut l1 = given_l1_at_height(current_height);
+        given_tx_state(&mut l1, stuck_tx, TxState::Pending);
+        l1.contract.expect_resubmit().never();
+
+        let pause = ContractPause::default();
+        pause.set_paused(true);
+        let mut monitor = TransactionMonitor::new(l1, db.clone(), GAS_BUMP, pause);
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap(), vec![stuck_tx]);
+    }
+
+    #[tokio::test]
+    async fn records_the_replaced_tx_if_it_is_mined_instead() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let (original, replacement) = given_replaced_tx_in_db(&db).await;
+
+        let mut l1 = given_l1_at_height(0);
+        given_tx_state(&mut l1, original, TxState::Mined);
+        given_tx_state(&mut l1, replacement, TxState::Pending);
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        assert!(db.unsettled_txs().await.unwrap().is_empty());
+        assert!(db.pending_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_polling_the_replaced_tx_while_it_may_still_be_mined() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        let (original, replacement) = given_replaced_tx_in_db(&db).await;
+
+        let mut l1 = given_l1_at_height(0);
+        given_tx_state(&mut l1, original, TxState::Pending);
+        given_tx_state(&mut l1, replacement, TxState::Dropped);
+
+        let mut monitor =
+            TransactionMonitor::new(l1, db.clone(), GAS_BUMP, ContractPause::default());
+
+        // when
+        monitor.run().await.unwrap();
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap(), vec![replacement]);
+        assert_eq!(db.unsettled_txs().await.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn fees_are_capped_when_bumped() {
+        let monitor = TransactionMonitor::new((), (), GAS_BUMP, ContractPause::default());
+
+        assert_eq!(
+            monitor.bumped_fees(&given_l1_tx(900, 100)),
+            Some((1_000, 120, None))
+        );
+        assert_eq!(
+            monitor.bumped_fees(&given_l1_tx(900, 880)),
+            Some((1_000, 1_000, None))
+        );
+    }
+
+    #[test]
+    fn blob_tx_fees_are_doubled_when_bumped() {
+        let monitor = TransactionMonitor::new((), (), GAS_BUMP, ContractPause::default());
+
+        assert_eq!(
+            monitor.bumped_fees(&given_blob_tx(100, 10, 7)),
+            Some((200, 20, Some(14)))
+        );
+        assert_eq!(monitor.bumped_fees(&given_blob_tx(600, 10, 7)), None);
+    }
+
+    #[test]
+    fn fees_at_cap_are_not_bumped() {
+        let monitor = TransactionMonitor::new((), (), GAS_BUMP, ContractPause::default());
+
+        assert_eq!(monitor.bumped_fees(&given_l1_tx(950, 100)), None);
+    }
+
+    fn given_l1_at_height(height: u64) -> MockL1 {
+        let mut l1 = MockL1::new();
+        l1.api
+            .expect_get_block_number()
+            .returning(move || Ok(height.try_into().unwrap()));
+        l1
+    }
+
+    fn given_tx_state(l1: &mut MockL1, tx: SubmissionTx, state: TxState) {
+        l1.api
+            .expect_tx_state()
+            .with(eq(tx.tx.hash))
+            .return_once(move |_| Ok(state));
+    }
+
+    fn given_l1_tx(max_fee_per_gas: u64, max_priority_fee_per_gas: u64) -> L1Tx {
+        L1Tx {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas: None,
+            ..rand::thread_rng().gen()
+        }
+    }
+
+    fn given_blob_tx(
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_blob_gas: u64,
+    ) -> L1Tx {
+        L1Tx {
+            max_fee_per_blob_gas: Some(max_fee_per_blob_gas),
+            ..given_l1_tx(max_fee_per_gas, max_priority_fee_per_gas)
+        }
+    }
+
+    async fn given_replaced_tx_in_db(db: &Postgres) -> (SubmissionTx, SubmissionTx) {
+        let original = given_pending_tx_in_db(db, given_l1_tx(100, 10)).await;
+        let replacement = SubmissionTx {
+            tx: L1Tx {
+                nonce: original.tx.nonce,
+                ..given_l1_tx(120, 12)
+            },
+            ..original
+        };
+        db.record_replacement(original.tx.hash, replacement)
+            .await
+            .unwrap();
+
+        (
+            SubmissionTx {
+                state: TxState::Replaced,
+                ..original
+            },
+            replacement,
+        )
+    }
+
+    async fn given_pending_tx_in_db(db: &Postgres, tx: L1Tx) -> SubmissionTx {
+        let mut rng = rand::thread_rng();
+        let submission: BlockSubmission = rng.gen();
+        db.insert(submission.clone()).await.unwrap();
+
+        let tx = SubmissionTx {
+            fuel_block_hash: submission.block.hash,
+            tx,
+            state: TxState::Pending,
+            submittal_height: rng.gen_range(0..1_000_000u32).into(),
+        };
+        db.insert_tx(tx).await.unwrap();
+
+        tx
+    }
+}
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS l1_submission_tx (
+    hash                        BYTEA PRIMARY KEY NOT NULL,
+    fuel_block_hash             BYTEA NOT NULL REFERENCES l1_fuel_block_submission(fuel_block_hash) ON DELETE CASCADE,
+    nonce                       BIGINT NOT NULL CHECK (nonce >= 0),
+    max_fee_per_gas             BIGINT NOT NULL CHECK (max_fee_per_gas >= 0),
+    max_priority_fee_per_gas    BIGINT NOT NULL CHECK (max_priority_fee_per_gas >= 0),
+    state                       SMALLINT NOT NULL,
+    submittal_height            BIGINT NOT NULL CHECK (submittal_height >= 0),
+    CHECK (octet_length(hash) = 32)
+);
+
+CREATE INDEX IF NOT EXISTS l1_submission_tx_state_idx ON l1_submission_tx (state);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS l1_fuel_block_submission_incomplete_idx;
+ALTER TABLE l1_fuel_block_submission DROP COLUMN IF EXISTS attempts;
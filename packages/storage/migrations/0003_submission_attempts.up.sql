@@ -0,0 +1,5 @@
+ALTER TABLE l1_fuel_block_submission
+    ADD COLUMN IF NOT EXISTS attempts INTEGER NOT NULL DEFAULT 1 CHECK (attempts >= 0);
+
+CREATE INDEX IF NOT EXISTS l1_fuel_block_submission_incomplete_idx
+    ON l1_fuel_block_submission (submittal_height) WHERE NOT completed;
@@ -0,0 +1,4 @@
+ALTER TABLE l1_fuel_block_submission
+    DROP COLUMN IF EXISTS commit_l1_height,
+    DROP COLUMN IF EXISTS commit_l1_block_hash,
+    DROP COLUMN IF EXISTS confirmed;
@@ -0,0 +1,8 @@
+ALTER TABLE l1_fuel_block_submission
+    ADD COLUMN IF NOT EXISTS commit_l1_height BIGINT CHECK (commit_l1_height >= 0),
+    ADD COLUMN IF NOT EXISTS commit_l1_block_hash BYTEA CHECK (octet_length(commit_l1_block_hash) = 32),
+    ADD COLUMN IF NOT EXISTS confirmed BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CHECK ((commit_l1_height IS NULL) = (commit_l1_block_hash IS NULL));
+
+-- submissions completed before the L1 block was tracked cannot be checked for reorgs anymore
+UPDATE l1_fuel_block_submission SET confirmed = completed;
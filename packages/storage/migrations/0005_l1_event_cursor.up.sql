@@ -0,0 +1,5 @@
+-- single row table, `id` only ever being true
+CREATE TABLE IF NOT EXISTS l1_event_cursor (
+    id                      BOOLEAN PRIMARY KEY NOT NULL DEFAULT TRUE CHECK (id),
+    last_processed_height   BIGINT NOT NULL CHECK (last_processed_height >= 0)
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS l1_foreign_commit (
+    fuel_block_hash         BYTEA PRIMARY KEY NOT NULL,
+    fuel_block_height       BIGINT NOT NULL CHECK (fuel_block_height >= 0),
+    commit_l1_height        BIGINT NOT NULL CHECK (commit_l1_height >= 0),
+    commit_l1_block_hash    BYTEA NOT NULL,
+    -- NULL while the fuel node doesn't know about the height
+    matches_fuel_chain      BOOLEAN,
+    CHECK (octet_length(fuel_block_hash) = 32),
+    CHECK (octet_length(commit_l1_block_hash) = 32)
+);
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS l1_commit_verification (
+    fuel_block_hash     BYTEA PRIMARY KEY NOT NULL REFERENCES l1_fuel_block_submission(fuel_block_hash) ON DELETE CASCADE,
+    -- NULL while the contract holds nothing at the commit height of the block
+    committed_hash      BYTEA CHECK (octet_length(committed_hash) = 32),
+    finalized           BOOLEAN NOT NULL
+);
@@ -0,0 +1,5 @@
+-- single row table, `id` only ever being true
+CREATE TABLE IF NOT EXISTS l1_nonce (
+    id                      BOOLEAN PRIMARY KEY NOT NULL DEFAULT TRUE CHECK (id),
+    last_used_nonce         BIGINT NOT NULL CHECK (last_used_nonce >= 0)
+);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS fuel_block_header (
+    hash                    BYTEA PRIMARY KEY NOT NULL,
+    height                  BIGINT NOT NULL CHECK (height >= 0),
+    prev_root               BYTEA NOT NULL,
+    time                    BIGINT NOT NULL CHECK (time >= 0),
+    da_height               BIGINT NOT NULL CHECK (da_height >= 0),
+    transactions_root       BYTEA NOT NULL,
+    message_outbox_root     BYTEA NOT NULL,
+    -- NULL for the genesis block
+    producer                BYTEA,
+    transactions_count      INTEGER NOT NULL CHECK (transactions_count >= 0),
+    application_hash        BYTEA NOT NULL,
+    CHECK (octet_length(hash) = 32),
+    CHECK (octet_length(prev_root) = 32),
+    CHECK (octet_length(transactions_root) = 32),
+    CHECK (octet_length(message_outbox_root) = 32),
+    CHECK (octet_length(producer) = 64),
+    CHECK (octet_length(application_hash) = 32)
+);
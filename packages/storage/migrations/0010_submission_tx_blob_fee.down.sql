@@ -0,0 +1 @@
+ALTER TABLE l1_submission_tx DROP COLUMN IF EXISTS max_fee_per_blob_gas;
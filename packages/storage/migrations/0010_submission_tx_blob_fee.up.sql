@@ -0,0 +1,3 @@
+-- NULL for transactions without blobs
+ALTER TABLE l1_submission_tx
+    ADD COLUMN IF NOT EXISTS max_fee_per_blob_gas BIGINT CHECK (max_fee_per_blob_gas >= 0);
@@ -7,7 +7,10 @@ pub use test_instance::*;
 
 mod error;
 mod postgres;
-use ports::types::BlockSubmission;
+use ports::types::{
+    BlockSubmission, CommitVerification, ForeignCommit, FuelBlockHeader, L1Block, L1Height,
+    SubmissionTx, TxState,
+};
 pub use postgres::*;
 
 #[async_trait::async_trait]
@@ -20,11 +23,156 @@ impl ports::storage::Storage for postgres::Postgres {
         Ok(self._submission_w_latest_block().await?)
     }
 
+    async fn latest_submissions(&self, limit: u32) -> ports::storage::Result<Vec<BlockSubmission>> {
+        Ok(self._latest_submissions(limit).await?)
+    }
+
+    async fn submission(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> ports::storage::Result<Option<BlockSubmission>> {
+        Ok(self._submission(fuel_block_hash).await?)
+    }
+
     async fn set_submission_completed(
         &self,
         fuel_block_hash: [u8; 32],
+        committed_in: L1Block,
+    ) -> ports::storage::Result<BlockSubmission> {
+        Ok(self
+            ._set_submission_completed(fuel_block_hash, committed_in)
+            .await?)
+    }
+
+    async fn unconfirmed_submissions(&self) -> ports::storage::Result<Vec<BlockSubmission>> {
+        Ok(self._unconfirmed_submissions().await?)
+    }
+
+    async fn set_submission_confirmed(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> ports::storage::Result<()> {
+        Ok(self._set_submission_confirmed(fuel_block_hash).await?)
+    }
+
+    async fn revert_submission_completion(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
+    ) -> ports::storage::Result<BlockSubmission> {
+        Ok(self
+            ._revert_submission_completion(fuel_block_hash, submittal_height)
+            .await?)
+    }
+
+    async fn oldest_incomplete_submission(
+        &self,
+    ) -> ports::storage::Result<Option<BlockSubmission>> {
+        Ok(self._oldest_incomplete_submission().await?)
+    }
+
+    async fn stale_submissions(
+        &self,
+        submitted_before: L1Height,
+    ) -> ports::storage::Result<Vec<BlockSubmission>> {
+        Ok(self._stale_submissions(submitted_before).await?)
+    }
+
+    async fn record_submission_attempt(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
     ) -> ports::storage::Result<BlockSubmission> {
-        Ok(self._set_submission_completed(fuel_block_hash).await?)
+        Ok(self
+            ._record_submission_attempt(fuel_block_hash, submittal_height)
+            .await?)
+    }
+
+    async fn insert_tx(&self, tx: SubmissionTx) -> ports::storage::Result<()> {
+        Ok(self._insert_tx(tx).await?)
+    }
+
+    async fn pending_txs(&self) -> ports::storage::Result<Vec<SubmissionTx>> {
+        Ok(self._pending_txs().await?)
+    }
+
+    async fn has_live_tx(&self, fuel_block_hash: [u8; 32]) -> ports::storage::Result<bool> {
+        Ok(self._has_live_tx(fuel_block_hash).await?)
+    }
+
+    async fn unsettled_txs(&self) -> ports::storage::Result<Vec<SubmissionTx>> {
+        Ok(self._unsettled_txs().await?)
+    }
+
+    async fn update_tx_state(
+        &self,
+        tx_hash: [u8; 32],
+        state: TxState,
+    ) -> ports::storage::Result<()> {
+        Ok(self._update_tx_state(tx_hash, state).await?)
+    }
+
+    async fn record_replacement(
+        &self,
+        replaced_tx_hash: [u8; 32],
+        tx: SubmissionTx,
+    ) -> ports::storage::Result<()> {
+        Ok(self._record_replacement(replaced_tx_hash, tx).await?)
+    }
+
+    async fn l1_event_cursor(&self) -> ports::storage::Result<Option<L1Height>> {
+        Ok(self._l1_event_cursor().await?)
+    }
+
+    async fn advance_l1_event_cursor(&self, height: L1Height) -> ports::storage::Result<()> {
+        Ok(self._advance_l1_event_cursor(height).await?)
+    }
+
+    async fn last_used_nonce(&self) -> ports::storage::Result<Option<u64>> {
+        Ok(self._last_used_nonce().await?)
+    }
+
+    async fn set_last_used_nonce(&self, nonce: u64) -> ports::storage::Result<()> {
+        Ok(self._set_last_used_nonce(nonce).await?)
+    }
+
+    async fn insert_foreign_commit(&self, commit: ForeignCommit) -> ports::storage::Result<()> {
+        Ok(self._insert_foreign_commit(commit).await?)
+    }
+
+    async fn foreign_commit(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> ports::storage::Result<Option<ForeignCommit>> {
+        Ok(self._foreign_commit(fuel_block_hash).await?)
+    }
+
+    async fn set_commit_verification(
+        &self,
+        verification: CommitVerification,
+    ) -> ports::storage::Result<()> {
+        Ok(self._set_commit_verification(verification).await?)
+    }
+
+    async fn commit_verification(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> ports::storage::Result<Option<CommitVerification>> {
+        Ok(self._commit_verification(fuel_block_hash).await?)
+    }
+
+    async fn insert_fuel_block_header(
+        &self,
+        header: FuelBlockHeader,
+    ) -> ports::storage::Result<()> {
+        Ok(self._insert_fuel_block_header(header).await?)
+    }
+
+    async fn fuel_block_header(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> ports::storage::Result<Option<FuelBlockHeader>> {
+        Ok(self._fuel_block_header(fuel_block_hash).await?)
     }
 }
 
@@ -32,7 +180,10 @@ impl ports::storage::Storage for postgres::Postgres {
 mod tests {
     use ports::{
         storage::{Error, Storage},
-        types::BlockSubmission,
+        types::{
+            BlockSubmission, CommitVerification, ForeignCommit, FuelBlockHeader, L1Block, L1Tx,
+            SubmissionTx, TxState,
+        },
     };
     use rand::{thread_rng, Rng};
     use storage as _;
@@ -64,6 +215,24 @@ mod tests {
         assert_eq!(actual, latest_submission);
     }
 
+    #[tokio::test]
+    async fn can_find_latest_submissions() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submissions = (1..=3).map(given_incomplete_submission).collect::<Vec<_>>();
+        for submission in &submissions {
+            db.insert(submission.clone()).await.unwrap();
+        }
+
+        // when
+        let latest = db.latest_submissions(2).await.unwrap();
+
+        // then
+        assert_eq!(latest, vec![submissions[2].clone(), submissions[1].clone()]);
+    }
+
     #[tokio::test]
     async fn can_update_completion_status() {
         // given
@@ -75,11 +244,18 @@ mod tests {
         let block_hash = submission.block.hash;
         db.insert(submission).await.unwrap();
 
+        let committed_in: L1Block = rand::thread_rng().gen();
+
         // when
-        let submission = db.set_submission_completed(block_hash).await.unwrap();
+        let submission = db
+            .set_submission_completed(block_hash, committed_in)
+            .await
+            .unwrap();
 
         // then
         assert!(submission.completed);
+        assert_eq!(submission.committed_in, Some(committed_in));
+        assert!(!submission.confirmed);
     }
 
     #[tokio::test]
@@ -93,7 +269,9 @@ mod tests {
         let block_hash = submission.block.hash;
 
         // when
-        let result = db.set_submission_completed(block_hash).await;
+        let result = db
+            .set_submission_completed(block_hash, rand::thread_rng().gen())
+            .await;
 
         // then
         let Err(Error::Database(msg)) = result else {
@@ -104,9 +282,502 @@ mod tests {
         assert_eq!(msg, format!("Cannot set submission to completed! Submission of block: `{block_hash}` not found in DB."));
     }
 
+    #[tokio::test]
+    async fn finds_unconfirmed_submissions() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let incomplete = given_incomplete_submission(1);
+        let unconfirmed = given_incomplete_submission(2);
+        let confirmed = given_incomplete_submission(3);
+        for submission in [&incomplete, &unconfirmed, &confirmed] {
+            db.insert(submission.clone()).await.unwrap();
+        }
+
+        let committed_in: L1Block = rand::thread_rng().gen();
+        let unconfirmed = db
+            .set_submission_completed(unconfirmed.block.hash, committed_in)
+            .await
+            .unwrap();
+        db.set_submission_completed(confirmed.block.hash, committed_in)
+            .await
+            .unwrap();
+        db.set_submission_confirmed(confirmed.block.hash)
+            .await
+            .unwrap();
+
+        // when
+        let unconfirmed_submissions = db.unconfirmed_submissions().await.unwrap();
+
+        // then
+        assert_eq!(unconfirmed_submissions, vec![unconfirmed]);
+    }
+
+    #[tokio::test]
+    async fn can_revert_submission_completion() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            attempts: 1,
+            ..given_incomplete_submission(random_non_zero_height())
+        };
+        db.insert(submission.clone()).await.unwrap();
+        db.set_submission_completed(submission.block.hash, rand::thread_rng().gen())
+            .await
+            .unwrap();
+
+        // when
+        let reverted = db
+            .revert_submission_completion(submission.block.hash, 42u32.into())
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(
+            reverted,
+            BlockSubmission {
+                attempts: 2,
+                submittal_height: 42u32.into(),
+                ..submission
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn reverting_submission_completion_drops_its_mined_txs() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        db.insert(submission.clone()).await.unwrap();
+        let mined_tx = given_tx(submission.block.hash, TxState::Mined);
+        db.insert_tx(mined_tx).await.unwrap();
+        db.set_submission_completed(submission.block.hash, rand::thread_rng().gen())
+            .await
+            .unwrap();
+
+        // when
+        db.revert_submission_completion(submission.block.hash, 42u32.into())
+            .await
+            .unwrap();
+
+        // then
+        assert!(!db.has_live_tx(submission.block.hash).await.unwrap());
+        assert_eq!(
+            db.stale_submissions(42u32.into()).await.unwrap(),
+            vec![db.submission(submission.block.hash).await.unwrap().unwrap()]
+        );
+    }
+
+    #[tokio::test]
+    async fn finds_stale_submissions_without_pending_or_mined_txs() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let stale = BlockSubmission {
+            submittal_height: 10u32.into(),
+            ..given_incomplete_submission(1)
+        };
+        let stale_w_pending_tx = BlockSubmission {
+            submittal_height: 10u32.into(),
+            ..given_incomplete_submission(2)
+        };
+        let recent = BlockSubmission {
+            submittal_height: 11u32.into(),
+            ..given_incomplete_submission(3)
+        };
+        let completed = BlockSubmission {
+            submittal_height: 10u32.into(),
+            completed: true,
+            ..given_incomplete_submission(4)
+        };
+        let stale_w_mined_tx = BlockSubmission {
+            submittal_height: 10u32.into(),
+            ..given_incomplete_submission(5)
+        };
+        for submission in [
+            &stale,
+            &stale_w_pending_tx,
+            &recent,
+            &completed,
+            &stale_w_mined_tx,
+        ] {
+            db.insert(submission.clone()).await.unwrap();
+        }
+        db.insert_tx(given_tx(stale_w_pending_tx.block.hash, TxState::Pending))
+            .await
+            .unwrap();
+        db.insert_tx(given_tx(stale_w_mined_tx.block.hash, TxState::Mined))
+            .await
+            .unwrap();
+
+        // when
+        let stale_submissions = db.stale_submissions(10u32.into()).await.unwrap();
+
+        // then
+        assert_eq!(stale_submissions, vec![stale]);
+    }
+
+    #[tokio::test]
+    async fn finds_oldest_incomplete_submission() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let oldest = BlockSubmission {
+            submittal_height: 5u32.into(),
+            ..given_incomplete_submission(2)
+        };
+        let newer = BlockSubmission {
+            submittal_height: 10u32.into(),
+            ..given_incomplete_submission(1)
+        };
+        let completed = BlockSubmission {
+            submittal_height: 1u32.into(),
+            completed: true,
+            ..given_incomplete_submission(3)
+        };
+        for submission in [&oldest, &newer, &completed] {
+            db.insert(submission.clone()).await.unwrap();
+        }
+
+        // when
+        let found = db.oldest_incomplete_submission().await.unwrap();
+
+        // then
+        assert_eq!(found, Some(oldest));
+    }
+
+    #[tokio::test]
+    async fn l1_event_cursor_only_moves_forward() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        assert_eq!(db.l1_event_cursor().await.unwrap(), None);
+
+        db.advance_l1_event_cursor(10u32.into()).await.unwrap();
+
+        // when
+        db.advance_l1_event_cursor(5u32.into()).await.unwrap();
+
+        // then
+        assert_eq!(db.l1_event_cursor().await.unwrap(), Some(10u32.into()));
+    }
+
+    #[tokio::test]
+    async fn last_used_nonce_is_overwritten() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+        assert_eq!(db.last_used_nonce().await.unwrap(), None);
+
+        db.set_last_used_nonce(10).await.unwrap();
+
+        // when
+        db.set_last_used_nonce(4).await.unwrap();
+
+        // then
+        assert_eq!(db.last_used_nonce().await.unwrap(), Some(4));
+    }
+
+    #[tokio::test]
+    async fn foreign_commit_is_replaced_when_seen_again() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let commit = ForeignCommit {
+            matches_fuel_chain: None,
+            ..rand::thread_rng().gen()
+        };
+        db.insert_foreign_commit(commit).await.unwrap();
+
+        let seen_again = ForeignCommit {
+            matches_fuel_chain: Some(false),
+            ..commit
+        };
+
+        // when
+        db.insert_foreign_commit(seen_again).await.unwrap();
+
+        // then
+        let stored = db.foreign_commit(commit.block.hash).await.unwrap();
+        assert_eq!(stored, Some(seen_again));
+    }
+
+    #[tokio::test]
+    async fn commit_verification_is_replaced_when_verified_again() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        db.insert(submission.clone()).await.unwrap();
+
+        let verification = CommitVerification {
+            fuel_block_hash: submission.block.hash,
+            committed_hash: None,
+            finalized: false,
+        };
+        db.set_commit_verification(verification).await.unwrap();
+
+        let verified_again = CommitVerification {
+            committed_hash: Some(submission.block.hash),
+            finalized: true,
+            ..verification
+        };
+
+        // when
+        db.set_commit_verification(verified_again).await.unwrap();
+
+        // then
+        let stored = db.commit_verification(submission.block.hash).await.unwrap();
+        assert_eq!(stored, Some(verified_again));
+    }
+
+    #[tokio::test]
+    async fn fuel_block_header_is_kept_when_inserted_again() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let header: FuelBlockHeader = rand::thread_rng().gen();
+        db.insert_fuel_block_header(header).await.unwrap();
+
+        // when
+        db.insert_fuel_block_header(FuelBlockHeader {
+            da_height: header.da_height / 2,
+            ..header
+        })
+        .await
+        .unwrap();
+
+        // then
+        let stored = db.fuel_block_header(header.block.hash).await.unwrap();
+        assert_eq!(stored, Some(header));
+    }
+
+    #[tokio::test]
+    async fn can_record_submission_attempt() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = BlockSubmission {
+            attempts: 1,
+            ..given_incomplete_submission(random_non_zero_height())
+        };
+        db.insert(submission.clone()).await.unwrap();
+
+        // when
+        let updated = db
+            .record_submission_attempt(submission.block.hash, 42u32.into())
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(
+            updated,
+            BlockSubmission {
+                attempts: 2,
+                submittal_height: 42u32.into(),
+                ..submission
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn finds_live_txs_of_a_block() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submissions = [1, 2, 3, 4].map(given_incomplete_submission);
+        for submission in &submissions {
+            db.insert(submission.clone()).await.unwrap();
+        }
+        let [w_pending_tx, w_mined_tx, w_reverted_tx, wo_tx] = submissions.map(|s| s.block.hash);
+        db.insert_tx(given_tx(w_pending_tx, TxState::Pending))
+            .await
+            .unwrap();
+        db.insert_tx(given_tx(w_mined_tx, TxState::Mined))
+            .await
+            .unwrap();
+        db.insert_tx(given_tx(w_reverted_tx, TxState::Reverted))
+            .await
+            .unwrap();
+
+        // when
+        let mut has_live_tx = vec![];
+        for hash in [w_pending_tx, w_mined_tx, w_reverted_tx, wo_tx] {
+            has_live_tx.push(db.has_live_tx(hash).await.unwrap());
+        }
+
+        // then
+        assert_eq!(has_live_tx, vec![true, true, false, false]);
+    }
+
+    #[tokio::test]
+    async fn can_track_pending_txs() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        let fuel_block_hash = submission.block.hash;
+        db.insert(submission).await.unwrap();
+
+        let pending_tx = given_tx(fuel_block_hash, TxState::Pending);
+        db.insert_tx(pending_tx).await.unwrap();
+        db.insert_tx(given_tx(fuel_block_hash, TxState::Mined))
+            .await
+            .unwrap();
+
+        // when
+        let pending = db.pending_txs().await.unwrap();
+
+        // then
+        assert_eq!(pending, vec![pending_tx]);
+    }
+
+    #[tokio::test]
+    async fn can_update_tx_state() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        let fuel_block_hash = submission.block.hash;
+        db.insert(submission).await.unwrap();
+
+        let tx = given_tx(fuel_block_hash, TxState::Pending);
+        db.insert_tx(tx).await.unwrap();
+
+        // when
+        db.update_tx_state(tx.tx.hash, TxState::Reverted)
+            .await
+            .unwrap();
+
+        // then
+        assert!(db.pending_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn replacement_supersedes_the_original_tx() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        let fuel_block_hash = submission.block.hash;
+        db.insert(submission).await.unwrap();
+
+        let original = given_tx(fuel_block_hash, TxState::Pending);
+        db.insert_tx(original).await.unwrap();
+
+        let replacement = given_tx(fuel_block_hash, TxState::Pending);
+
+        // when
+        db.record_replacement(original.tx.hash, replacement)
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(db.pending_txs().await.unwrap(), vec![replacement]);
+    }
+
+    #[tokio::test]
+    async fn replaced_txs_stay_unsettled_while_their_nonce_is_pending() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        let fuel_block_hash = submission.block.hash;
+        db.insert(submission).await.unwrap();
+
+        let original = given_tx(fuel_block_hash, TxState::Pending);
+        db.insert_tx(original).await.unwrap();
+        let replacement = SubmissionTx {
+            tx: L1Tx {
+                nonce: original.tx.nonce,
+                ..thread_rng().gen()
+            },
+            ..given_tx(fuel_block_hash, TxState::Pending)
+        };
+        db.record_replacement(original.tx.hash, replacement)
+            .await
+            .unwrap();
+
+        let unrelated_replaced_tx = given_tx(fuel_block_hash, TxState::Replaced);
+        db.insert_tx(unrelated_replaced_tx).await.unwrap();
+
+        // when
+        let unsettled = db.unsettled_txs().await.unwrap();
+
+        // then
+        let unsettled = unsettled
+            .into_iter()
+            .map(|tx| (tx.tx.hash, tx.state))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unsettled,
+            vec![
+                (original.tx.hash, TxState::Replaced),
+                (replacement.tx.hash, TxState::Pending)
+            ]
+        );
+
+        // and once the nonce is settled
+        db.update_tx_state(replacement.tx.hash, TxState::Mined)
+            .await
+            .unwrap();
+        assert!(db.unsettled_txs().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_replacement_leaves_the_original_tx_pending() {
+        // given
+        let process = PostgresProcess::shared().await.unwrap();
+        let db = process.create_random_db().await.unwrap();
+
+        let submission = given_incomplete_submission(random_non_zero_height());
+        let fuel_block_hash = submission.block.hash;
+        db.insert(submission).await.unwrap();
+
+        let original = given_tx(fuel_block_hash, TxState::Pending);
+        db.insert_tx(original).await.unwrap();
+
+        // replacement references an unknown submission
+        let replacement = given_tx([0; 32], TxState::Pending);
+
+        // when
+        let result = db.record_replacement(original.tx.hash, replacement).await;
+
+        // then
+        assert!(result.is_err());
+        assert_eq!(db.pending_txs().await.unwrap(), vec![original]);
+    }
+
+    fn given_tx(fuel_block_hash: [u8; 32], state: TxState) -> SubmissionTx {
+        SubmissionTx {
+            fuel_block_hash,
+            state,
+            ..rand::thread_rng().gen()
+        }
+    }
+
     fn given_incomplete_submission(fuel_block_height: u32) -> BlockSubmission {
         let mut submission = rand::thread_rng().gen::<BlockSubmission>();
         submission.block.height = fuel_block_height;
+        submission.completed = false;
+        submission.committed_in = None;
+        submission.confirmed = false;
 
         submission
     }
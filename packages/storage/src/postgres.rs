@@ -1,4 +1,7 @@
-use ports::types::BlockSubmission;
+use ports::types::{
+    BlockSubmission, CommitVerification, ForeignCommit, FuelBlockHeader, L1Block, L1Height,
+    SubmissionTx, TxState,
+};
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 
 use super::error::{Error, Result};
@@ -64,13 +67,17 @@ impl Postgres {
     }
 
     pub(crate) async fn _insert(&self, submission: BlockSubmission) -> crate::error::Result<()> {
-        let row = tables::L1FuelBlockSubmission::from(submission);
+        let row = tables::L1FuelBlockSubmission::try_from(submission)?;
         sqlx::query!(
-            "INSERT INTO l1_fuel_block_submission (fuel_block_hash, fuel_block_height, completed, submittal_height) VALUES ($1, $2, $3, $4)",
+            "INSERT INTO l1_fuel_block_submission (fuel_block_hash, fuel_block_height, completed, submittal_height, attempts, commit_l1_height, commit_l1_block_hash, confirmed) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
             row.fuel_block_hash,
             row.fuel_block_height,
             row.completed,
-            row.submittal_height
+            row.submittal_height,
+            row.attempts,
+            row.commit_l1_height,
+            row.commit_l1_block_hash,
+            row.confirmed
         ).execute(&self.connection_pool).await?;
         Ok(())
     }
@@ -88,13 +95,97 @@ impl Postgres {
         .transpose()
     }
 
+    pub(crate) async fn _latest_submissions(&self, limit: u32) -> Result<Vec<BlockSubmission>> {
+        sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "SELECT * FROM l1_fuel_block_submission ORDER BY fuel_block_height DESC LIMIT $1",
+            i64::from(limit)
+        )
+        .fetch_all(&self.connection_pool)
+        .await?
+        .into_iter()
+        .map(BlockSubmission::try_from)
+        .collect()
+    }
+
+    pub(crate) async fn _submission(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> Result<Option<BlockSubmission>> {
+        sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "SELECT * FROM l1_fuel_block_submission WHERE fuel_block_hash = $1",
+            fuel_block_hash.as_slice(),
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(BlockSubmission::try_from)
+        .transpose()
+    }
+
+    pub(crate) async fn _oldest_incomplete_submission(&self) -> Result<Option<BlockSubmission>> {
+        sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "SELECT * FROM l1_fuel_block_submission WHERE NOT completed ORDER BY submittal_height ASC LIMIT 1"
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(BlockSubmission::try_from)
+        .transpose()
+    }
+
+    pub(crate) async fn _stale_submissions(
+        &self,
+        submitted_before: L1Height,
+    ) -> Result<Vec<BlockSubmission>> {
+        let pending = tables::L1SubmissionTx::encode_state(TxState::Pending);
+        let mined = tables::L1SubmissionTx::encode_state(TxState::Mined);
+        sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "SELECT * FROM l1_fuel_block_submission s WHERE NOT s.completed AND s.submittal_height <= $1 AND NOT EXISTS (SELECT 1 FROM l1_submission_tx t WHERE t.fuel_block_hash = s.fuel_block_hash AND t.state IN ($2, $3)) ORDER BY s.fuel_block_height ASC",
+            i64::from(submitted_before),
+            pending,
+            mined
+        )
+        .fetch_all(&self.connection_pool)
+        .await?
+        .into_iter()
+        .map(BlockSubmission::try_from)
+        .collect()
+    }
+
+    pub(crate) async fn _record_submission_attempt(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
+    ) -> Result<BlockSubmission> {
+        let updated_row = sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "UPDATE l1_fuel_block_submission SET attempts = attempts + 1, submittal_height = $1 WHERE fuel_block_hash = $2 RETURNING *",
+            i64::from(submittal_height),
+            fuel_block_hash.as_slice(),
+        ).fetch_optional(&self.connection_pool).await?;
+
+        if let Some(row) = updated_row {
+            Ok(row.try_into()?)
+        } else {
+            let hash = hex::encode(fuel_block_hash);
+            Err(Error::Database(format!(
+                "Cannot record submission attempt! Submission of block: `{hash}` not found in DB."
+            )))
+        }
+    }
+
     pub(crate) async fn _set_submission_completed(
         &self,
         fuel_block_hash: [u8; 32],
+        committed_in: L1Block,
     ) -> Result<BlockSubmission> {
         let updated_row = sqlx::query_as!(
             tables::L1FuelBlockSubmission,
-            "UPDATE l1_fuel_block_submission SET completed = true WHERE fuel_block_hash = $1 RETURNING *",
+            "UPDATE l1_fuel_block_submission SET completed = true, commit_l1_height = $1, commit_l1_block_hash = $2, confirmed = false WHERE fuel_block_hash = $3 RETURNING *",
+            i64::from(committed_in.height),
+            committed_in.hash.as_slice(),
             fuel_block_hash.as_slice(),
         ).fetch_optional(&self.connection_pool).await?;
 
@@ -105,4 +196,344 @@ impl Postgres {
             Err(Error::Database(format!("Cannot set submission to completed! Submission of block: `{hash}` not found in DB.")))
         }
     }
+
+    pub(crate) async fn _unconfirmed_submissions(&self) -> Result<Vec<BlockSubmission>> {
+        sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "SELECT * FROM l1_fuel_block_submission WHERE completed AND NOT confirmed ORDER BY commit_l1_height ASC"
+        )
+        .fetch_all(&self.connection_pool)
+        .await?
+        .into_iter()
+        .map(BlockSubmission::try_from)
+        .collect()
+    }
+
+    pub(crate) async fn _set_submission_confirmed(&self, fuel_block_hash: [u8; 32]) -> Result<()> {
+        let updated = sqlx::query!(
+            "UPDATE l1_fuel_block_submission SET confirmed = true WHERE fuel_block_hash = $1 AND completed",
+            fuel_block_hash.as_slice(),
+        )
+        .execute(&self.connection_pool)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            let hash = hex::encode(fuel_block_hash);
+            return Err(Error::Database(format!("Cannot set submission to confirmed! Completed submission of block: `{hash}` not found in DB.")));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn _revert_submission_completion(
+        &self,
+        fuel_block_hash: [u8; 32],
+        submittal_height: L1Height,
+    ) -> Result<BlockSubmission> {
+        let mut db_tx = self.connection_pool.begin().await?;
+
+        let updated_row = sqlx::query_as!(
+            tables::L1FuelBlockSubmission,
+            "UPDATE l1_fuel_block_submission SET completed = false, commit_l1_height = NULL, commit_l1_block_hash = NULL, confirmed = false, attempts = attempts + 1, submittal_height = $1 WHERE fuel_block_hash = $2 RETURNING *",
+            i64::from(submittal_height),
+            fuel_block_hash.as_slice(),
+        ).fetch_optional(&mut *db_tx).await?;
+
+        let Some(row) = updated_row else {
+            let hash = hex::encode(fuel_block_hash);
+            return Err(Error::Database(format!("Cannot revert submission completion! Submission of block: `{hash}` not found in DB.")));
+        };
+
+        // the mined txs went down with the reorged out blocks, otherwise they'd keep the block
+        // from being committed again
+        let mined = tables::L1SubmissionTx::encode_state(TxState::Mined);
+        let dropped = tables::L1SubmissionTx::encode_state(TxState::Dropped);
+        sqlx::query!(
+            "UPDATE l1_submission_tx SET state = $1 WHERE fuel_block_hash = $2 AND state = $3",
+            dropped,
+            fuel_block_hash.as_slice(),
+            mined
+        )
+        .execute(&mut *db_tx)
+        .await?;
+
+        db_tx.commit().await?;
+
+        row.try_into()
+    }
+
+    pub(crate) async fn _insert_tx(&self, tx: SubmissionTx) -> Result<()> {
+        let mut db_tx = self.connection_pool.begin().await?;
+        Self::insert_tx_row(&mut db_tx, tx).await?;
+        db_tx.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_tx_row(
+        db_tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tx: SubmissionTx,
+    ) -> Result<()> {
+        let row = tables::L1SubmissionTx::try_from(tx)?;
+        sqlx::query!(
+            "INSERT INTO l1_submission_tx (hash, fuel_block_hash, nonce, max_fee_per_gas, max_priority_fee_per_gas, state, submittal_height, max_fee_per_blob_gas) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            row.hash,
+            row.fuel_block_hash,
+            row.nonce,
+            row.max_fee_per_gas,
+            row.max_priority_fee_per_gas,
+            row.state,
+            row.submittal_height,
+            row.max_fee_per_blob_gas
+        ).execute(&mut **db_tx).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn _pending_txs(&self) -> Result<Vec<SubmissionTx>> {
+        let pending = tables::L1SubmissionTx::encode_state(TxState::Pending);
+        sqlx::query_as!(
+            tables::L1SubmissionTx,
+            "SELECT * FROM l1_submission_tx WHERE state = $1 ORDER BY nonce ASC",
+            pending
+        )
+        .fetch_all(&self.connection_pool)
+        .await?
+        .into_iter()
+        .map(SubmissionTx::try_from)
+        .collect()
+    }
+
+    pub(crate) async fn _has_live_tx(&self, fuel_block_hash: [u8; 32]) -> Result<bool> {
+        let pending = tables::L1SubmissionTx::encode_state(TxState::Pending);
+        let mined = tables::L1SubmissionTx::encode_state(TxState::Mined);
+        let exists = sqlx::query_scalar!(
+            "SELECT EXISTS (SELECT 1 FROM l1_submission_tx WHERE fuel_block_hash = $1 AND state IN ($2, $3))",
+            fuel_block_hash.as_slice(),
+            pending,
+            mined
+        )
+        .fetch_one(&self.connection_pool)
+        .await?;
+
+        Ok(exists.unwrap_or(false))
+    }
+
+    pub(crate) async fn _unsettled_txs(&self) -> Result<Vec<SubmissionTx>> {
+        let pending = tables::L1SubmissionTx::encode_state(TxState::Pending);
+        let replaced = tables::L1SubmissionTx::encode_state(TxState::Replaced);
+        sqlx::query_as!(
+            tables::L1SubmissionTx,
+            "SELECT * FROM l1_submission_tx WHERE state = $1 OR (state = $2 AND nonce IN (SELECT nonce FROM l1_submission_tx WHERE state = $1)) ORDER BY nonce ASC, state DESC, submittal_height ASC",
+            pending,
+            replaced
+        )
+        .fetch_all(&self.connection_pool)
+        .await?
+        .into_iter()
+        .map(SubmissionTx::try_from)
+        .collect()
+    }
+
+    pub(crate) async fn _update_tx_state(&self, tx_hash: [u8; 32], state: TxState) -> Result<()> {
+        let mut db_tx = self.connection_pool.begin().await?;
+        Self::update_tx_state_row(&mut db_tx, tx_hash, state).await?;
+        db_tx.commit().await?;
+        Ok(())
+    }
+
+    pub(crate) async fn _record_replacement(
+        &self,
+        replaced_tx_hash: [u8; 32],
+        tx: SubmissionTx,
+    ) -> Result<()> {
+        let mut db_tx = self.connection_pool.begin().await?;
+        Self::update_tx_state_row(&mut db_tx, replaced_tx_hash, TxState::Replaced).await?;
+        Self::insert_tx_row(&mut db_tx, tx).await?;
+        db_tx.commit().await?;
+        Ok(())
+    }
+
+    pub(crate) async fn _l1_event_cursor(&self) -> Result<Option<L1Height>> {
+        let height = sqlx::query_scalar!("SELECT last_processed_height FROM l1_event_cursor")
+            .fetch_optional(&self.connection_pool)
+            .await?;
+
+        height
+            .map(|height| {
+                L1Height::try_from(height).map_err(|e| {
+                    Error::Conversion(format!(
+                        "`last_processed_height` as read from the db is invalid: {e}"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    pub(crate) async fn _advance_l1_event_cursor(&self, height: L1Height) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO l1_event_cursor (last_processed_height) VALUES ($1) ON CONFLICT (id) DO UPDATE SET last_processed_height = GREATEST(l1_event_cursor.last_processed_height, EXCLUDED.last_processed_height)",
+            i64::from(height),
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn _last_used_nonce(&self) -> Result<Option<u64>> {
+        let nonce = sqlx::query_scalar!("SELECT last_used_nonce FROM l1_nonce")
+            .fetch_optional(&self.connection_pool)
+            .await?;
+
+        nonce
+            .map(|nonce| {
+                u64::try_from(nonce).map_err(|_| {
+                    Error::Conversion(format!(
+                        "`last_used_nonce` as read from the db cannot fit in a `u64` as expected. Got: {nonce} from db"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    pub(crate) async fn _set_last_used_nonce(&self, nonce: u64) -> Result<()> {
+        let nonce = i64::try_from(nonce).map_err(|_| {
+            Error::Conversion(format!(
+                "nonce {nonce} cannot fit in a `i64` as expected by the db"
+            ))
+        })?;
+
+        sqlx::query!(
+            "INSERT INTO l1_nonce (last_used_nonce) VALUES ($1) ON CONFLICT (id) DO UPDATE SET last_used_nonce = EXCLUDED.last_used_nonce",
+            nonce,
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn _insert_foreign_commit(&self, commit: ForeignCommit) -> Result<()> {
+        let row = tables::L1ForeignCommit::from(commit);
+        sqlx::query!(
+            "INSERT INTO l1_foreign_commit (fuel_block_hash, fuel_block_height, commit_l1_height, commit_l1_block_hash, matches_fuel_chain) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (fuel_block_hash) DO UPDATE SET fuel_block_height = EXCLUDED.fuel_block_height, commit_l1_height = EXCLUDED.commit_l1_height, commit_l1_block_hash = EXCLUDED.commit_l1_block_hash, matches_fuel_chain = EXCLUDED.matches_fuel_chain",
+            row.fuel_block_hash,
+            row.fuel_block_height,
+            row.commit_l1_height,
+            row.commit_l1_block_hash,
+            row.matches_fuel_chain
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn _foreign_commit(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> Result<Option<ForeignCommit>> {
+        sqlx::query_as!(
+            tables::L1ForeignCommit,
+            "SELECT * FROM l1_foreign_commit WHERE fuel_block_hash = $1",
+            fuel_block_hash.as_slice(),
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(ForeignCommit::try_from)
+        .transpose()
+    }
+
+    pub(crate) async fn _insert_fuel_block_header(&self, header: FuelBlockHeader) -> Result<()> {
+        let row = tables::FuelHeader::try_from(header)?;
+        sqlx::query!(
+            "INSERT INTO fuel_block_header (hash, height, prev_root, time, da_height, transactions_root, message_outbox_root, producer, transactions_count, application_hash) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (hash) DO NOTHING",
+            row.hash,
+            row.height,
+            row.prev_root,
+            row.time,
+            row.da_height,
+            row.transactions_root,
+            row.message_outbox_root,
+            row.producer,
+            row.transactions_count,
+            row.application_hash
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn _fuel_block_header(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> Result<Option<FuelBlockHeader>> {
+        sqlx::query_as!(
+            tables::FuelHeader,
+            "SELECT * FROM fuel_block_header WHERE hash = $1",
+            fuel_block_hash.as_slice(),
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(FuelBlockHeader::try_from)
+        .transpose()
+    }
+
+    pub(crate) async fn _set_commit_verification(
+        &self,
+        verification: CommitVerification,
+    ) -> Result<()> {
+        let row = tables::L1CommitVerification::from(verification);
+        sqlx::query!(
+            "INSERT INTO l1_commit_verification (fuel_block_hash, committed_hash, finalized) VALUES ($1, $2, $3) ON CONFLICT (fuel_block_hash) DO UPDATE SET committed_hash = EXCLUDED.committed_hash, finalized = EXCLUDED.finalized",
+            row.fuel_block_hash,
+            row.committed_hash,
+            row.finalized
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn _commit_verification(
+        &self,
+        fuel_block_hash: [u8; 32],
+    ) -> Result<Option<CommitVerification>> {
+        sqlx::query_as!(
+            tables::L1CommitVerification,
+            "SELECT * FROM l1_commit_verification WHERE fuel_block_hash = $1",
+            fuel_block_hash.as_slice(),
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(CommitVerification::try_from)
+        .transpose()
+    }
+
+    async fn update_tx_state_row(
+        db_tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tx_hash: [u8; 32],
+        state: TxState,
+    ) -> Result<()> {
+        let state = tables::L1SubmissionTx::encode_state(state);
+        let updated = sqlx::query!(
+            "UPDATE l1_submission_tx SET state = $1 WHERE hash = $2",
+            state,
+            tx_hash.as_slice(),
+        )
+        .execute(&mut **db_tx)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            let hash = hex::encode(tx_hash);
+            return Err(Error::Database(format!(
+                "Cannot update tx state! Tx: `{hash}` not found in DB."
+            )));
+        }
+
+        Ok(())
+    }
 }
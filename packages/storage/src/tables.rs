@@ -1,4 +1,7 @@
-use ports::types::{BlockSubmission, FuelBlock};
+use ports::types::{
+    BlockSubmission, CommitVerification, ForeignCommit, FuelBlock, FuelBlockHeader, L1Block, L1Tx,
+    SubmissionTx, TxState,
+};
 
 #[derive(sqlx::FromRow)]
 pub struct L1FuelBlockSubmission {
@@ -6,6 +9,10 @@ pub struct L1FuelBlockSubmission {
     pub fuel_block_height: i64,
     pub completed: bool,
     pub submittal_height: i64,
+    pub attempts: i32,
+    pub commit_l1_height: Option<i64>,
+    pub commit_l1_block_hash: Option<Vec<u8>>,
+    pub confirmed: bool,
 }
 
 impl TryFrom<L1FuelBlockSubmission> for BlockSubmission {
@@ -34,21 +41,425 @@ impl TryFrom<L1FuelBlockSubmission> for BlockSubmission {
             bail!("`submittal_height` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.submittal_height);
         };
 
+        let Ok(attempts) = value.attempts.try_into() else {
+            bail!(
+                "`attempts` as read from the db cannot fit in a `u32` as expected. Got: {} from db",
+                value.attempts
+            );
+        };
+
+        let committed_in = match (value.commit_l1_height, value.commit_l1_block_hash) {
+            (Some(height), Some(hash)) => {
+                let Ok(height) = height.try_into() else {
+                    bail!("`commit_l1_height` as read from the db cannot fit in a `u64` as expected. Got: {} from db", height);
+                };
+                let Ok(hash) = hash.as_slice().try_into() else {
+                    bail!(
+                        "Expected 32 bytes for `commit_l1_block_hash`, but got: {:?} from db",
+                        hash
+                    );
+                };
+                Some(L1Block { height, hash })
+            }
+            (None, None) => None,
+            (height, hash) => {
+                bail!("`commit_l1_height` and `commit_l1_block_hash` must be set together. Got: {:?} and {:?} from db", height, hash);
+            }
+        };
+
         Ok(Self {
             block: FuelBlock { hash, height },
             completed: value.completed,
             submittal_height,
+            attempts,
+            committed_in,
+            confirmed: value.confirmed,
         })
     }
 }
 
-impl From<BlockSubmission> for L1FuelBlockSubmission {
-    fn from(value: BlockSubmission) -> Self {
-        Self {
+impl TryFrom<BlockSubmission> for L1FuelBlockSubmission {
+    type Error = crate::error::Error;
+
+    fn try_from(value: BlockSubmission) -> Result<Self, Self::Error> {
+        let attempts = i32::try_from(value.attempts).map_err(|_| {
+            Self::Error::Conversion(format!(
+                "`attempts` of {} too large. DB can handle at most {}",
+                value.attempts,
+                i32::MAX
+            ))
+        })?;
+
+        Ok(Self {
             fuel_block_hash: value.block.hash.to_vec(),
             fuel_block_height: i64::from(value.block.height),
             completed: value.completed,
             submittal_height: value.submittal_height.into(),
+            attempts,
+            commit_l1_height: value.committed_in.map(|block| block.height.into()),
+            commit_l1_block_hash: value.committed_in.map(|block| block.hash.to_vec()),
+            confirmed: value.confirmed,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct L1SubmissionTx {
+    pub hash: Vec<u8>,
+    pub fuel_block_hash: Vec<u8>,
+    pub nonce: i64,
+    pub max_fee_per_gas: i64,
+    pub max_priority_fee_per_gas: i64,
+    pub state: i16,
+    pub submittal_height: i64,
+    pub max_fee_per_blob_gas: Option<i64>,
+}
+
+impl L1SubmissionTx {
+    pub fn encode_state(state: TxState) -> i16 {
+        match state {
+            TxState::Pending => 0,
+            TxState::Mined => 1,
+            TxState::Reverted => 2,
+            TxState::Dropped => 3,
+            TxState::Replaced => 4,
+        }
+    }
+}
+
+impl TryFrom<L1SubmissionTx> for SubmissionTx {
+    type Error = crate::error::Error;
+
+    fn try_from(value: L1SubmissionTx) -> Result<Self, Self::Error> {
+        macro_rules! bail {
+            ($msg: literal, $($args: expr),*) => {
+                return Err(Self::Error::Conversion(format!($msg, $($args),*)));
+            };
+        }
+        let Ok(hash) = value.hash.as_slice().try_into() else {
+            bail!(
+                "Expected 32 bytes for `hash`, but got: {:?} from db",
+                value.hash
+            );
+        };
+
+        let Ok(fuel_block_hash) = value.fuel_block_hash.as_slice().try_into() else {
+            bail!(
+                "Expected 32 bytes for `fuel_block_hash`, but got: {:?} from db",
+                value.fuel_block_hash
+            );
+        };
+
+        let Ok(nonce) = value.nonce.try_into() else {
+            bail!(
+                "`nonce` as read from the db cannot fit in a `u64` as expected. Got: {} from db",
+                value.nonce
+            );
+        };
+
+        let Ok(max_fee_per_gas) = value.max_fee_per_gas.try_into() else {
+            bail!("`max_fee_per_gas` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.max_fee_per_gas);
+        };
+
+        let Ok(max_priority_fee_per_gas) = value.max_priority_fee_per_gas.try_into() else {
+            bail!("`max_priority_fee_per_gas` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.max_priority_fee_per_gas);
+        };
+
+        let state = match value.state {
+            0 => TxState::Pending,
+            1 => TxState::Mined,
+            2 => TxState::Reverted,
+            3 => TxState::Dropped,
+            4 => TxState::Replaced,
+            _ => {
+                bail!(
+                    "Unknown `state` read from the db. Got: {} from db",
+                    value.state
+                );
+            }
+        };
+
+        let Ok(submittal_height) = value.submittal_height.try_into() else {
+            bail!("`submittal_height` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.submittal_height);
+        };
+
+        let Ok(max_fee_per_blob_gas) = value.max_fee_per_blob_gas.map(u64::try_from).transpose()
+        else {
+            bail!("`max_fee_per_blob_gas` as read from the db cannot fit in a `u64` as expected. Got: {:?} from db", value.max_fee_per_blob_gas);
+        };
+
+        Ok(Self {
+            fuel_block_hash,
+            tx: L1Tx {
+                hash,
+                nonce,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            },
+            state,
+            submittal_height,
+        })
+    }
+}
+
+impl TryFrom<SubmissionTx> for L1SubmissionTx {
+    type Error = crate::error::Error;
+
+    fn try_from(value: SubmissionTx) -> Result<Self, Self::Error> {
+        let to_db_int = |name: &str, value: u64| {
+            i64::try_from(value).map_err(|_| {
+                Self::Error::Conversion(format!(
+                    "`{name}` of {value} too large. DB can handle at most {}",
+                    i64::MAX
+                ))
+            })
+        };
+
+        Ok(Self {
+            hash: value.tx.hash.to_vec(),
+            fuel_block_hash: value.fuel_block_hash.to_vec(),
+            nonce: to_db_int("nonce", value.tx.nonce)?,
+            max_fee_per_gas: to_db_int("max_fee_per_gas", value.tx.max_fee_per_gas)?,
+            max_priority_fee_per_gas: to_db_int(
+                "max_priority_fee_per_gas",
+                value.tx.max_priority_fee_per_gas,
+            )?,
+            state: Self::encode_state(value.state),
+            submittal_height: value.submittal_height.into(),
+            max_fee_per_blob_gas: value
+                .tx
+                .max_fee_per_blob_gas
+                .map(|fee| to_db_int("max_fee_per_blob_gas", fee))
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct L1ForeignCommit {
+    pub fuel_block_hash: Vec<u8>,
+    pub fuel_block_height: i64,
+    pub commit_l1_height: i64,
+    pub commit_l1_block_hash: Vec<u8>,
+    pub matches_fuel_chain: Option<bool>,
+}
+
+impl TryFrom<L1ForeignCommit> for ForeignCommit {
+    type Error = crate::error::Error;
+
+    fn try_from(value: L1ForeignCommit) -> Result<Self, Self::Error> {
+        macro_rules! bail {
+            ($msg: literal, $($args: expr),*) => {
+                return Err(Self::Error::Conversion(format!($msg, $($args),*)));
+            };
+        }
+        let Ok(hash) = value.fuel_block_hash.as_slice().try_into() else {
+            bail!(
+                "Expected 32 bytes for `fuel_block_hash`, but got: {:?} from db",
+                value.fuel_block_hash
+            );
+        };
+
+        let Ok(height) = value.fuel_block_height.try_into() else {
+            bail!("`fuel_block_height` as read from the db cannot fit in a `u32` as expected. Got: {} from db", value.fuel_block_height);
+        };
+
+        let Ok(commit_l1_height) = value.commit_l1_height.try_into() else {
+            bail!("`commit_l1_height` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.commit_l1_height);
+        };
+
+        let Ok(commit_l1_block_hash) = value.commit_l1_block_hash.as_slice().try_into() else {
+            bail!(
+                "Expected 32 bytes for `commit_l1_block_hash`, but got: {:?} from db",
+                value.commit_l1_block_hash
+            );
+        };
+
+        Ok(Self {
+            block: FuelBlock { hash, height },
+            included_in: L1Block {
+                height: commit_l1_height,
+                hash: commit_l1_block_hash,
+            },
+            matches_fuel_chain: value.matches_fuel_chain,
+        })
+    }
+}
+
+impl From<ForeignCommit> for L1ForeignCommit {
+    fn from(value: ForeignCommit) -> Self {
+        Self {
+            fuel_block_hash: value.block.hash.to_vec(),
+            fuel_block_height: i64::from(value.block.height),
+            commit_l1_height: value.included_in.height.into(),
+            commit_l1_block_hash: value.included_in.hash.to_vec(),
+            matches_fuel_chain: value.matches_fuel_chain,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct L1CommitVerification {
+    pub fuel_block_hash: Vec<u8>,
+    pub committed_hash: Option<Vec<u8>>,
+    pub finalized: bool,
+}
+
+impl TryFrom<L1CommitVerification> for CommitVerification {
+    type Error = crate::error::Error;
+
+    fn try_from(value: L1CommitVerification) -> Result<Self, Self::Error> {
+        macro_rules! bail {
+            ($msg: literal, $($args: expr),*) => {
+                return Err(Self::Error::Conversion(format!($msg, $($args),*)));
+            };
+        }
+        let Ok(fuel_block_hash) = value.fuel_block_hash.as_slice().try_into() else {
+            bail!(
+                "Expected 32 bytes for `fuel_block_hash`, but got: {:?} from db",
+                value.fuel_block_hash
+            );
+        };
+
+        let committed_hash = match value.committed_hash {
+            Some(hash) => {
+                let Ok(hash) = hash.as_slice().try_into() else {
+                    bail!(
+                        "Expected 32 bytes for `committed_hash`, but got: {:?} from db",
+                        hash
+                    );
+                };
+                Some(hash)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            fuel_block_hash,
+            committed_hash,
+            finalized: value.finalized,
+        })
+    }
+}
+
+impl From<CommitVerification> for L1CommitVerification {
+    fn from(value: CommitVerification) -> Self {
+        Self {
+            fuel_block_hash: value.fuel_block_hash.to_vec(),
+            committed_hash: value.committed_hash.map(|hash| hash.to_vec()),
+            finalized: value.finalized,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct FuelHeader {
+    pub hash: Vec<u8>,
+    pub height: i64,
+    pub prev_root: Vec<u8>,
+    pub time: i64,
+    pub da_height: i64,
+    pub transactions_root: Vec<u8>,
+    pub message_outbox_root: Vec<u8>,
+    pub producer: Option<Vec<u8>>,
+    pub transactions_count: i32,
+    pub application_hash: Vec<u8>,
+}
+
+impl TryFrom<FuelHeader> for FuelBlockHeader {
+    type Error = crate::error::Error;
+
+    fn try_from(value: FuelHeader) -> Result<Self, Self::Error> {
+        macro_rules! bail {
+            ($msg: literal, $($args: expr),*) => {
+                return Err(Self::Error::Conversion(format!($msg, $($args),*)));
+            };
         }
+        let to_hash = |name: &str, bytes: &[u8]| -> Result<[u8; 32], Self::Error> {
+            bytes.try_into().map_err(|_| {
+                Self::Error::Conversion(format!(
+                    "Expected 32 bytes for `{name}`, but got: {bytes:?} from db"
+                ))
+            })
+        };
+
+        let Ok(height) = value.height.try_into() else {
+            bail!(
+                "`height` as read from the db cannot fit in a `u32` as expected. Got: {} from db",
+                value.height
+            );
+        };
+
+        let Ok(time) = value.time.try_into() else {
+            bail!(
+                "`time` as read from the db cannot fit in a `u64` as expected. Got: {} from db",
+                value.time
+            );
+        };
+
+        let Ok(da_height) = value.da_height.try_into() else {
+            bail!("`da_height` as read from the db cannot fit in a `u64` as expected. Got: {} from db", value.da_height);
+        };
+
+        let producer = match value.producer {
+            Some(producer) => {
+                let Ok(producer) = producer.as_slice().try_into() else {
+                    bail!(
+                        "Expected 64 bytes for `producer`, but got: {:?} from db",
+                        producer
+                    );
+                };
+                Some(producer)
+            }
+            None => None,
+        };
+
+        let Ok(transactions_count) = value.transactions_count.try_into() else {
+            bail!("`transactions_count` as read from the db cannot fit in a `u16` as expected. Got: {} from db", value.transactions_count);
+        };
+
+        Ok(Self {
+            block: FuelBlock {
+                hash: to_hash("hash", &value.hash)?,
+                height,
+            },
+            prev_root: to_hash("prev_root", &value.prev_root)?,
+            time,
+            da_height,
+            transactions_root: to_hash("transactions_root", &value.transactions_root)?,
+            message_outbox_root: to_hash("message_outbox_root", &value.message_outbox_root)?,
+            producer,
+            transactions_count,
+            application_hash: to_hash("application_hash", &value.application_hash)?,
+        })
+    }
+}
+
+impl TryFrom<FuelBlockHeader> for FuelHeader {
+    type Error = crate::error::Error;
+
+    fn try_from(value: FuelBlockHeader) -> Result<Self, Self::Error> {
+        let to_db_int = |name: &str, value: u64| {
+            i64::try_from(value).map_err(|_| {
+                Self::Error::Conversion(format!(
+                    "`{name}` of {value} too large. DB can handle at most {}",
+                    i64::MAX
+                ))
+            })
+        };
+
+        Ok(Self {
+            hash: value.block.hash.to_vec(),
+            height: i64::from(value.block.height),
+            prev_root: value.prev_root.to_vec(),
+            time: to_db_int("time", value.time)?,
+            da_height: to_db_int("da_height", value.da_height)?,
+            transactions_root: value.transactions_root.to_vec(),
+            message_outbox_root: value.message_outbox_root.to_vec(),
+            producer: value.producer.map(|producer| producer.to_vec()),
+            transactions_count: i32::from(value.transactions_count),
+            application_hash: value.application_hash.to_vec(),
+        })
     }
 }